@@ -1,18 +1,31 @@
+#[cfg(feature = "serialization")]
+use crate::serialization::{
+    migrations, AnimatedSpriteState, RestoreStateError, CURRENT_SCHEMA_VERSION,
+};
+use crate::{ripple_ring, should_drop_frame, silhouette_material, typewriter_revealed_fraction};
 use crate::{
-    Animation, AnimationEffectTrait, AnimationQueueEntry, EffectDuration, EffectTimeTarget,
-    InternalEffectsState, Seconds, X, Y,
+    AnimatedSpriteBuilder, AnimatedSpriteError, AnimatedSpriteResult, Animation, AnimationEffect,
+    AnimationEffectTrait, AnimationQueueEntry, AnimationValidationError, EffectDuration,
+    EffectTimeTarget, FlipDirection, InternalEffectsState, LoopMode, Seconds, SlideDirection,
+    UnknownKeyError, X, Y,
 };
+#[cfg(feature = "callbacks")]
+use crate::{SpriteCallback, SpriteCallbacks};
 use glam::Vec2;
-use macroquad::color::Color;
+use macroquad::color::{Color, WHITE};
+use macroquad::logging::warn;
+use macroquad::material::{gl_use_default_material, gl_use_material};
 use macroquad::math::Rect;
+use macroquad::shapes::draw_rectangle;
 use macroquad::texture::{draw_texture_ex, DrawTextureParams, Texture2D};
 use macroquad::time::get_frame_time;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Display};
 use std::hash::Hash;
 
 /// AnimatedSprite is the core struct that allows for animating a single sprite using multiple Animations stored inside.
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AnimatedSprite<K: Eq + Hash + Clone> {
     tile_width: f32,
     tile_height: f32,
@@ -25,9 +38,37 @@ pub struct AnimatedSprite<K: Eq + Hash + Clone> {
     current_queue_time: EffectDuration,
     playing_time: EffectDuration,
     paused: bool,
+    #[serde(default)]
+    effect_paused: bool,
     current_animation_key: K,
     previous_animation_key: Option<K>,
     effects_state: InternalEffectsState,
+    #[serde(default)]
+    draw_offset: (X, Y),
+    #[serde(default = "default_draw_scale")]
+    draw_scale: f32,
+    #[serde(default)]
+    anchor: (f32, f32),
+    #[serde(with = "color_rgba", default = "default_base_color")]
+    base_color: Color,
+    #[serde(skip)]
+    preferred_texture: Option<Texture2D>,
+    #[serde(default)]
+    render_layer: i32,
+    /// Temporary effect applied on top of the current animation: (effect, duration, elapsed time)
+    #[serde(default)]
+    override_effect: Option<(AnimationEffect, Seconds, Seconds)>,
+    #[serde(default)]
+    loop_mode: LoopMode,
+    #[serde(default)]
+    ping_pong_reversing: bool,
+    #[serde(default)]
+    animation_completed: bool,
+    #[serde(default)]
+    current_animation_loop_count: u32,
+    #[cfg(feature = "callbacks")]
+    #[serde(skip)]
+    callbacks: SpriteCallbacks,
 }
 
 impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
@@ -39,6 +80,7 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         default_animation_key: K,
         default_animation: Animation,
     ) -> Self {
+        Self::_warn_if_invalid(&default_animation);
         let mut animations = HashMap::new();
         animations.insert(default_animation_key.clone(), default_animation);
 
@@ -54,12 +96,34 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
             current_queue_time: 0.0,
             playing_time: 0.0,
             paused: false,
+            effect_paused: false,
             current_animation_key: default_animation_key.clone(),
             previous_animation_key: None,
             effects_state: InternalEffectsState::new(),
+            draw_offset: (0.0, 0.0),
+            draw_scale: 1.0,
+            anchor: (0.0, 0.0),
+            base_color: WHITE,
+            preferred_texture: None,
+            render_layer: 0,
+            override_effect: None,
+            loop_mode: LoopMode::Forward,
+            ping_pong_reversing: false,
+            animation_completed: false,
+            current_animation_loop_count: 0,
+            #[cfg(feature = "callbacks")]
+            callbacks: SpriteCallbacks::default(),
         }
     }
 
+    /// Creates a builder for configuring an AnimatedSprite fluently, starting from its default animation.
+    pub fn with_default_animation_builder(
+        default_animation_key: K,
+        default_animation: Animation,
+    ) -> AnimatedSpriteBuilder<K> {
+        AnimatedSpriteBuilder::new(default_animation_key, default_animation)
+    }
+
     /// Internal method, starts a new animation, resetting relevant fields and initializing effects.
     fn start_new_animation(&mut self, key: K, animation_duration: Seconds) {
         self.previous_animation_key = Some(self.current_animation_key.clone());
@@ -68,10 +132,16 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         self.current_animation_loop_time = 0.0;
         self.current_animation_time = 0.0;
         self.current_queue_time = 0.0;
+        self.ping_pong_reversing = false;
+        self.animation_completed = false;
+        self.current_animation_loop_count = 0;
         self.effects_state.reset();
 
         if let Some(new_animation) = self.animations.get(&self.current_animation_key) {
-            if let Some((_, target)) = &new_animation.effect {
+            self.current_frame = self
+                ._get_loop_mode_for(new_animation)
+                .first_frame(new_animation.total_frames());
+            if let Some((_, target, _)) = &new_animation.effect {
                 match target {
                     EffectTimeTarget::Start(duration) => {
                         let capped_duration = duration.min(animation_duration);
@@ -85,6 +155,11 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
                         self.effects_state.is_active = false;
                         self.effects_state.effect_start_time = animation_duration - capped_duration;
                     }
+                    EffectTimeTarget::Repeating { duration, .. } => {
+                        self.effects_state.current_effect_duration = *duration;
+                        self.effects_state.is_active = true;
+                        self.effects_state.effect_start_time = 0.0;
+                    }
                 }
             } else {
                 self.effects_state.is_active = false;
@@ -95,43 +170,275 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
     }
 
     /// Sets the default animation of the sprite, referencing a previously registered Animation.
+    #[deprecated(note = "use `set_default_animation_result` instead")]
     pub fn set_default_animation(&mut self, key: K) -> Option<&mut Self> {
-        if self.animations.contains_key(&key) {
-            self.default_animation_key = key.clone();
-            if self.animation_queue.is_empty() {
-                self.start_new_animation(key, f32::MAX);
-            }
-            Some(self)
-        } else {
-            None
+        self.set_default_animation_result(key).ok()
+    }
+
+    /// Sets the default animation of the sprite, referencing a previously registered Animation.
+    /// Returns `UnknownKey` if no animation is registered under `key`.
+    pub fn set_default_animation_result(&mut self, key: K) -> AnimatedSpriteResult<&mut Self, K> {
+        if !self.animations.contains_key(&key) {
+            return Err(AnimatedSpriteError::UnknownKey(key));
+        }
+
+        self.default_animation_key = key.clone();
+        if self.animation_queue.is_empty() {
+            self.start_new_animation(key, f32::MAX);
         }
+        Ok(self)
     }
 
     /// Registers an animation in the sprite which can later be used as either the default, or part of the animation queue.
-    /// Of note, registering another animation under the same key will replace the old one.
+    /// Of note, registering another animation under the same key will replace the old one (use `replace_animation` to get the old one back).
+    /// In debug builds, a warning is logged if the animation fails `Animation::validate`.
     pub fn register_animation(&mut self, key: K, animation: Animation) -> &mut Self {
+        Self::_warn_if_invalid(&animation);
         self.animations.insert(key, animation);
         self
     }
 
+    /// Same as `register_animation`, but returns the error and registers nothing if the animation fails `Animation::validate`.
+    pub fn register_animation_checked(
+        &mut self,
+        key: K,
+        animation: Animation,
+    ) -> Result<&mut Self, AnimationValidationError> {
+        animation.validate()?;
+        self.animations.insert(key, animation);
+        Ok(self)
+    }
+
+    /// Internal, logs a warning in debug builds if the animation fails `Animation::validate`.
+    fn _warn_if_invalid(animation: &Animation) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        if let Err(error) = animation.validate() {
+            warn!("registered an invalid animation: {}", error);
+        }
+    }
+
+    /// Registers multiple animations in the sprite at once (ex. the output of one of the importers).
+    /// Of note, registering another animation under the same key will replace the old one.
+    pub fn register_animations_batch<I>(&mut self, animations: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, Animation)>,
+    {
+        self.animations.extend(
+            animations
+                .into_iter()
+                .inspect(|(_, animation)| Self::_warn_if_invalid(animation)),
+        );
+        self
+    }
+
+    /// Copies the animation registered under `key` into `dest` (ex. from a template sprite), returning `dest`.
+    /// Returns None if no animation is registered under `key`.
+    #[deprecated(note = "use `clone_animation_to_result` instead")]
+    pub fn clone_animation_to<'a>(
+        &self,
+        key: &K,
+        dest: &'a mut AnimatedSprite<K>,
+    ) -> Option<&'a mut AnimatedSprite<K>> {
+        self.clone_animation_to_result(key, dest).ok()
+    }
+
+    /// Copies the animation registered under `key` into `dest` (ex. from a template sprite), returning `dest`.
+    /// Returns `UnknownKey` if no animation is registered under `key`.
+    pub fn clone_animation_to_result<'a>(
+        &self,
+        key: &K,
+        dest: &'a mut AnimatedSprite<K>,
+    ) -> AnimatedSpriteResult<&'a mut AnimatedSprite<K>, K> {
+        let animation = self
+            .animations
+            .get(key)
+            .ok_or_else(|| AnimatedSpriteError::UnknownKey(key.clone()))?
+            .clone();
+        Ok(dest.register_animation(key.clone(), animation))
+    }
+
+    /// Registers all animations of `other` in this sprite (ex. merging an accessory overlay's animations into a base body).
+    /// Of note, `other`'s animations replace any registered under the same key.
+    pub fn merge_animations_from(&mut self, other: &AnimatedSprite<K>) -> &mut Self {
+        self.register_animations_batch(
+            other
+                .animations
+                .iter()
+                .map(|(key, animation)| (key.clone(), animation.clone())),
+        )
+    }
+
+    /// Returns a copy of the sprite with every registered animation replaced by its `reversed()` version (ex. for a time-reversal mechanic).
+    /// The queue, default animation and all playback timing are copied as is.
+    pub fn with_all_animations_reversed(&self) -> AnimatedSprite<K> {
+        let mut sprite = self.clone();
+        for animation in sprite.animations.values_mut() {
+            *animation = animation.reversed();
+        }
+        sprite
+    }
+
+    /// Registers an animation in the sprite, returning the animation previously registered under the same key (if any).
+    pub fn replace_animation(&mut self, key: K, animation: Animation) -> Option<Animation> {
+        Self::_warn_if_invalid(&animation);
+        self.animations.insert(key, animation)
+    }
+
     /// Deletes a registered animation from the sprite by its key.
     pub fn delete_animation(&mut self, key: &K) -> &mut Self {
         self.animations.remove(key);
         self
     }
 
+    /// Returns the number of animations registered in the sprite.
+    pub fn animation_count(&self) -> usize {
+        self.animations.len()
+    }
+
+    /// Returns the total number of frames across all registered animations, useful for validating a texture atlas.
+    pub fn total_registered_frame_count(&self) -> u32 {
+        self.animations.values().map(Animation::total_frames).sum()
+    }
+
+    /// Returns the highest spritesheet row referenced by any registered animation, useful for checking the texture is tall enough.
+    /// Animations made from explicit rects don't use rows, so they are left out. Returns None if no animation uses rows.
+    pub fn max_row_used(&self) -> Option<u32> {
+        self.animations
+            .values()
+            .filter(|animation| animation.explicit_rects.is_none())
+            .flat_map(|animation| animation.rows.iter().copied())
+            .max()
+    }
+
+    /// Checks if an animation is registered in the sprite under the given key.
+    pub fn is_animation_registered(&self, key: &K) -> bool {
+        self.animations.contains_key(key)
+    }
+
+    /// Returns the total number of frames of the animation registered under `key`, or None if no animation is registered under it.
+    pub fn frame_count_for(&self, key: &K) -> Option<u32> {
+        self.animations.get(key).map(Animation::total_frames)
+    }
+
+    /// Returns the fps of the animation registered under `key`, or None if no animation is registered under it.
+    pub fn fps_for(&self, key: &K) -> Option<u32> {
+        self.animations.get(key).map(|animation| animation.fps)
+    }
+
+    /// Returns the seconds it takes to play through the animation registered under `key` once (ex. to pick a duration for
+    /// `add_animation_to_queue`), or None if no animation is registered under it.
+    pub fn animation_duration_for(&self, key: &K) -> Option<Seconds> {
+        self.animations
+            .get(key)
+            .map(Animation::total_duration_seconds)
+    }
+
+    /// Returns an iterator over all registered animations and their keys.
+    pub fn iter_animations(&self) -> impl Iterator<Item = (&K, &Animation)> {
+        self.animations.iter()
+    }
+
+    /// Returns an iterator over the keys of all registered animations.
+    pub fn iter_animation_keys(&self) -> impl Iterator<Item = &K> {
+        self.animations.keys()
+    }
+
+    /// Returns an iterator over all registered animations.
+    pub fn iter_animation_values(&self) -> impl Iterator<Item = &Animation> {
+        self.animations.values()
+    }
+
     /// Adds an animation to the queue. This will queue it up to be played for a `duration` number of seconds automatically.
+    #[deprecated(note = "use `add_animation_to_queue_result` instead")]
     pub fn add_animation_to_queue(&mut self, key: K, duration: Seconds) -> Option<&mut Self> {
-        if self.animations.contains_key(&key) {
-            self.animation_queue.push_back((key.clone(), duration));
+        self.add_animation_to_queue_result(key, duration).ok()
+    }
 
-            if self.animation_queue.len() == 1 {
-                self.start_new_animation(key, duration);
-            }
-            Some(self)
-        } else {
-            None
+    /// Adds an animation to the queue. This will queue it up to be played for a `duration` number of seconds automatically.
+    /// Returns `UnknownKey` if no animation is registered under `key`.
+    pub fn add_animation_to_queue_result(
+        &mut self,
+        key: K,
+        duration: Seconds,
+    ) -> AnimatedSpriteResult<&mut Self, K> {
+        if !self.animations.contains_key(&key) {
+            return Err(AnimatedSpriteError::UnknownKey(key));
+        }
+
+        self._push_to_queue(key, duration);
+        Ok(self)
+    }
+
+    /// Internal, adds the animation registered under `key` to the back of the queue, starting it if the queue was empty.
+    fn _push_to_queue(&mut self, key: K, duration: Seconds) {
+        self.animation_queue.push_back((key.clone(), duration));
+        if self.animation_queue.len() == 1 {
+            self.start_new_animation(key, duration);
+        }
+    }
+
+    /// Adds a sequence of animations to the queue, each paired with the duration it should be played for.
+    /// If any of the keys are not registered, nothing is added to the queue and None is returned.
+    #[deprecated(note = "use `queue_sequence_result` instead")]
+    pub fn queue_sequence<'a, I>(&mut self, pairs: I) -> Option<&mut Self>
+    where
+        I: IntoIterator<Item = &'a (K, Seconds)>,
+        K: 'a,
+    {
+        self.queue_sequence_result(pairs).ok()
+    }
+
+    /// Adds a sequence of animations to the queue, each paired with the duration it should be played for.
+    /// If any of the keys are not registered, nothing is added to the queue and `UnknownKey` is returned for the first one.
+    pub fn queue_sequence_result<'a, I>(&mut self, pairs: I) -> AnimatedSpriteResult<&mut Self, K>
+    where
+        I: IntoIterator<Item = &'a (K, Seconds)>,
+        K: 'a,
+    {
+        let pairs: Vec<&(K, Seconds)> = pairs.into_iter().collect();
+        if let Some((key, _)) = pairs
+            .iter()
+            .find(|(key, _)| !self.animations.contains_key(key))
+        {
+            return Err(AnimatedSpriteError::UnknownKey(key.clone()));
+        }
+
+        for (key, duration) in pairs {
+            self.add_animation_to_queue_result(key.clone(), *duration)?;
+        }
+        Ok(self)
+    }
+
+    /// Switches to the animation registered under `key` for `blend_duration` seconds once the current frame finishes,
+    /// rather than cutting the current animation off mid-frame. Anything queued after the current animation plays after it.
+    /// Returns None if no animation is registered under `key`.
+    #[deprecated(note = "use `transition_to_result` instead")]
+    pub fn transition_to(&mut self, key: K, blend_duration: Seconds) -> Option<&mut Self> {
+        self.transition_to_result(key, blend_duration).ok()
+    }
+
+    /// Same as `transition_to`, but returns `UnknownKey` if no animation is registered under `key`.
+    pub fn transition_to_result(
+        &mut self,
+        key: K,
+        blend_duration: Seconds,
+    ) -> AnimatedSpriteResult<&mut Self, K> {
+        if !self.animations.contains_key(&key) {
+            return Err(AnimatedSpriteError::UnknownKey(key));
+        }
+
+        // The current animation stays at the front of the queue, now only until its current frame ends
+        let current_duration = self.current_queue_time + self.get_remaining_frame_time();
+        match self.animation_queue.front_mut() {
+            Some((_, duration)) => *duration = duration.min(current_duration),
+            None => self
+                .animation_queue
+                .push_back((self.current_animation_key.clone(), current_duration)),
         }
+        self.animation_queue.insert(1, (key, blend_duration));
+        Ok(self)
     }
 
     /// Immediately moves to the next animation in the queue, dropping the current one even if the duration has not finished.
@@ -168,6 +475,144 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         self.paused
     }
 
+    /// Pauses the progression of the current effect, while frames continue to animate as normal.
+    /// Of note, a queued animation will not move on while its effect is held.
+    pub fn pause_effect(&mut self) -> &mut Self {
+        self.effect_paused = true;
+        self
+    }
+
+    /// If the current effect is paused, this resumes its progression.
+    pub fn resume_effect(&mut self) -> &mut Self {
+        self.effect_paused = false;
+        self
+    }
+
+    /// Checks if effect progression is paused.
+    pub fn is_effect_paused(&self) -> bool {
+        self.effect_paused
+    }
+
+    /// Returns the seconds left until the current effect finishes (ex. to spawn particles just before it ends).
+    /// Returns None if no effect is active.
+    pub fn effect_remaining_time(&self) -> Option<Seconds> {
+        if !self.effects_state.is_active {
+            return None;
+        }
+        Some(self.effects_state.remaining_time())
+    }
+
+    /// Applies a temporary effect on top of whatever animation is playing for `duration` seconds (ex. a hit flash),
+    /// without changing the registered animations or the queue. Applying another one replaces it.
+    /// Of note, the effect keeps playing across animation changes and is held while effects are paused. Effects which draw
+    /// extra copies of the sprite (ex. Mirror, Ripple) or swap materials (Silhouette) only have their base adjustments applied.
+    pub fn apply_effect_once(&mut self, effect: AnimationEffect, duration: Seconds) -> &mut Self {
+        self.override_effect = Some((effect, duration, 0.0));
+        self
+    }
+
+    /// Sets the direction frames advance in, and what happens once the last frame is reached (`LoopMode::Forward` by default).
+    /// Of note, this applies to every animation played by the sprite, and takes effect from the current frame.
+    pub fn set_loop_mode(&mut self, mode: LoopMode) -> &mut Self {
+        self.loop_mode = mode;
+        self.ping_pong_reversing = false;
+        self
+    }
+
+    /// Returns the current loop mode.
+    pub fn get_loop_mode(&self) -> LoopMode {
+        self.loop_mode
+    }
+
+    /// Returns how many full loops the current animation has played through since it started (ex. to wait for two idle loops).
+    pub fn get_animation_loop_count(&self) -> u32 {
+        self.current_animation_loop_count
+    }
+
+    /// Resets the loop count of the current animation back to 0.
+    pub fn reset_loop_count(&mut self) -> &mut Self {
+        self.current_animation_loop_count = 0;
+        self
+    }
+
+    /// Internal, gets the loop mode the provided animation plays with, as animations which hold their last frame always play once.
+    fn _get_loop_mode_for(&self, animation: &Animation) -> LoopMode {
+        if animation.hold_last_frame {
+            LoopMode::Once
+        } else {
+            self.loop_mode
+        }
+    }
+
+    /// Checks if the current animation has played through to its end and is holding its last frame (ex. with `LoopMode::Once`).
+    pub fn is_animation_complete(&self) -> bool {
+        self.animation_completed
+    }
+
+    /// Registers a callback which is called when the current animation plays through to its end and holds its last frame
+    /// (ex. with `LoopMode::Once`). Callbacks are called in registration order, and are not cloned or serialized with the sprite.
+    #[cfg(feature = "callbacks")]
+    pub fn on_animation_complete(&mut self, callback: SpriteCallback) -> &mut Self {
+        self.callbacks.on_complete_callbacks.push(callback);
+        self
+    }
+
+    /// Registers a callback which is called every time playback advances onto a new frame (ex. to sync particles with the animation).
+    /// Callbacks are called in registration order, before those registered through `on_specific_frame` for the new frame,
+    /// and are not cloned or serialized with the sprite.
+    #[cfg(feature = "callbacks")]
+    pub fn on_frame_change(&mut self, callback: SpriteCallback) -> &mut Self {
+        self.callbacks.frame_change_callbacks.push(callback);
+        self
+    }
+
+    /// Removes every callback registered through `on_frame_change`.
+    #[cfg(feature = "callbacks")]
+    pub fn clear_frame_change_callbacks(&mut self) -> &mut Self {
+        self.callbacks.frame_change_callbacks.clear();
+        self
+    }
+
+    /// Registers a callback which is called whenever playback advances onto the given frame index (ex. a footstep sound).
+    /// Multiple callbacks for the same frame are called in registration order, and are not cloned or serialized with the sprite.
+    /// Of note, this applies to every animation played by the sprite.
+    #[cfg(feature = "callbacks")]
+    pub fn on_specific_frame(&mut self, frame: u32, callback: SpriteCallback) -> &mut Self {
+        self.callbacks
+            .frame_event_map
+            .entry(frame)
+            .or_default()
+            .push(callback);
+        self
+    }
+
+    /// Removes every callback registered through `on_specific_frame` (ex. when reusing a sprite across scenes).
+    #[cfg(feature = "callbacks")]
+    pub fn clear_frame_callbacks(&mut self) -> &mut Self {
+        self.callbacks.frame_event_map.clear();
+        self
+    }
+
+    /// Removes the callbacks registered through `on_specific_frame` for the given frame index.
+    #[cfg(feature = "callbacks")]
+    pub fn clear_specific_frame_callbacks(&mut self, frame: u32) -> &mut Self {
+        self.callbacks.frame_event_map.remove(&frame);
+        self
+    }
+
+    /// Removes every callback registered through `on_animation_complete`.
+    #[cfg(feature = "callbacks")]
+    pub fn clear_animation_complete_callbacks(&mut self) -> &mut Self {
+        self.callbacks.on_complete_callbacks.clear();
+        self
+    }
+
+    /// Checks if the current animation's effect has finished playing (ex. to wait for an effect before moving on in a state machine).
+    /// Of note, repeating effects never complete.
+    pub fn is_current_effect_complete(&self) -> bool {
+        self.effects_state.is_complete()
+    }
+
     /// Checks if the animation queue is empty.
     pub fn is_queue_empty(&self) -> bool {
         self.animation_queue.is_empty()
@@ -181,6 +626,18 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
             .unwrap_or(&self.default_animation_key)
     }
 
+    /// Checks if the animation registered under `key` is the one currently playing, which is the one being drawn.
+    pub fn is_playing_animation(&self, key: &K) -> bool {
+        &self.current_animation_key == key
+    }
+
+    /// Checks if the animation registered under `key` appears anywhere in the queue, including the front.
+    pub fn is_animation_queued(&self, key: &K) -> bool {
+        self.animation_queue
+            .iter()
+            .any(|(queued_key, _)| queued_key == key)
+    }
+
     /// Gets the current animation.
     pub fn get_current_animation(&self) -> Option<Animation> {
         self.animations
@@ -189,12 +646,43 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
     }
 
     /// Sets the current frame of the animation, potentially missing multiple frames and thus having a jarring visual effect.
+    /// Frames past the end of the animation wrap back around to the start.
+    #[deprecated(note = "use `set_frame_result` instead")]
     pub fn set_frame(&mut self, frame: u32) -> Option<&mut Self> {
-        let animation = self.get_current_animation()?;
-        self.current_frame = frame % animation.total_frames();
+        let total_frames = self.get_current_animation()?.total_frames();
+        if total_frames == 0 {
+            return None;
+        }
+        self.current_frame = frame % total_frames;
         Some(self)
     }
 
+    /// Sets the current frame of the animation, potentially missing multiple frames and thus having a jarring visual effect.
+    /// Unlike `set_frame`, frames past the end of the animation return `InvalidFrameIndex` rather than wrapping around.
+    pub fn set_frame_result(&mut self, frame: u32) -> AnimatedSpriteResult<&mut Self, K> {
+        let total_frames = self._get_current_animation_result()?.total_frames();
+        if total_frames == 0 {
+            return Err(AnimatedSpriteError::EmptyAnimation);
+        }
+        if frame >= total_frames {
+            return Err(AnimatedSpriteError::InvalidFrameIndex {
+                requested: frame,
+                max: total_frames - 1,
+            });
+        }
+
+        self.current_frame = frame;
+        Ok(self)
+    }
+
+    /// Internal, gets the current animation or an `UnknownKey` error if it isn't registered.
+    fn _get_current_animation_result(&self) -> AnimatedSpriteResult<&Animation, K> {
+        let key = self.get_current_animation_key();
+        self.animations
+            .get(key)
+            .ok_or_else(|| AnimatedSpriteError::UnknownKey(key.clone()))
+    }
+
     /// Checks if the current frame is the last frame of the animation.
     pub fn is_last_frame(&self) -> bool {
         if let Some(animation) = self.get_current_animation() {
@@ -204,16 +692,69 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         }
     }
 
+    /// Scrubs the current effect to the given progress (0.0 to 1.0), allowing you to preview an effect at any point.
+    pub fn scrub_effect_to(&mut self, progress: f32) -> &mut Self {
+        self.effects_state.set_progress(progress);
+        self
+    }
+
+    /// Immediately completes the current effect, skipping any remaining duration so the post-effect state is drawn next frame.
+    pub fn force_complete_effect(&mut self) -> &mut Self {
+        self.effects_state.effect_time = self.effects_state.current_effect_duration;
+        self.effects_state.is_active = false;
+        self.effects_state.has_played = true;
+        self
+    }
+
+    /// Replays the current animation's effect from the start without resetting the current frame or the queue.
+    /// Returns None if the current animation has no effect.
+    #[deprecated(note = "use `replay_current_effect_result` instead")]
+    pub fn replay_current_effect(&mut self) -> Option<&mut Self> {
+        self.replay_current_effect_result().ok()
+    }
+
+    /// Replays the current animation's effect from the start without resetting the current frame or the queue.
+    /// Returns `NoEffect` if the current animation has no effect.
+    pub fn replay_current_effect_result(&mut self) -> AnimatedSpriteResult<&mut Self, K> {
+        let animation = self
+            .animations
+            .get(&self.current_animation_key)
+            .ok_or_else(|| AnimatedSpriteError::UnknownKey(self.current_animation_key.clone()))?;
+        if animation.effect.is_none() {
+            return Err(AnimatedSpriteError::NoEffect);
+        }
+
+        self.effects_state.effect_time = 0.0;
+        self.effects_state.is_active = true;
+        self.effects_state.has_played = false;
+        Ok(self)
+    }
+
     /// Update must be called continuously by your application to ensure your AnimatedSprite changes frames/animates.
     /// This handles the internal logic for dealing with the animation queue and providing the draw methods with the correct frame.
     pub fn update(&mut self) -> &mut Self {
+        self.update_with_dt(get_frame_time())
+    }
+
+    /// Same as `update`, but advances the AnimatedSprite by a provided delta time (in seconds) instead of macroquad's frame time.
+    /// Useful for fixed timestep game loops, or for stepping a sprite outside of a running macroquad window (ex. in tests).
+    pub fn update_with_dt(&mut self, dt: Seconds) -> &mut Self {
         if self.paused {
             return self;
         }
 
-        let dt = get_frame_time();
         self.playing_time += dt;
-        self.current_animation_loop_time += dt;
+        if !self.effect_paused {
+            if let Some((_, duration, elapsed)) = &mut self.override_effect {
+                *elapsed += dt;
+                if *elapsed >= *duration {
+                    self.override_effect = None;
+                }
+            }
+        }
+        if !self._is_frame_frozen() {
+            self.current_animation_loop_time += dt;
+        }
         self.current_animation_time += dt;
         self.current_queue_time += dt;
 
@@ -226,9 +767,16 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
             }
         }
 
+        let mut is_repeating_effect = false;
+
         if let Some(animation) = self.animations.get(&self.current_animation_key) {
+            is_repeating_effect = matches!(
+                animation.effect,
+                Some((_, EffectTimeTarget::Repeating { .. }, _))
+            );
+
             // Handle effect activation
-            if let Some((_, target)) = &animation.effect {
+            if let Some((_, target, _)) = &animation.effect {
                 match target {
                     EffectTimeTarget::Start(_) => {
                         if !self.effects_state.is_active && !self.effects_state.has_played {
@@ -245,11 +793,22 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
                             self.effects_state.effect_time = 0.0;
                         }
                     }
+                    EffectTimeTarget::Repeating { period, duration } => {
+                        if !self.effect_paused {
+                            let time_in_period = if *period > 0.0 {
+                                self.current_animation_time % period
+                            } else {
+                                0.0
+                            };
+                            self.effects_state.is_active = time_in_period < *duration;
+                            self.effects_state.effect_time = time_in_period;
+                        }
+                    }
                 }
             }
 
-            // Update effect state
-            if self.effects_state.is_active {
+            // Update effect state (repeating effects are driven by the animation time above instead)
+            if self.effects_state.is_active && !self.effect_paused && !is_repeating_effect {
                 self.effects_state.effect_time += dt;
                 if self.effects_state.effect_time >= self.effects_state.current_effect_duration {
                     self.effects_state.is_active = false;
@@ -258,10 +817,39 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
             }
 
             // Handle frame update
-            let frame_duration = 1.0 / animation.fps as f32;
+            let loop_mode = self._get_loop_mode_for(animation);
+            let total_frames = animation.total_frames();
+            let mut frame_duration = animation.frame_duration(self.current_frame);
             while self.current_animation_loop_time >= frame_duration {
-                self.current_frame = (self.current_frame + 1) % animation.total_frames();
                 self.current_animation_loop_time -= frame_duration;
+                match loop_mode.next_frame(
+                    self.current_frame,
+                    total_frames,
+                    &mut self.ping_pong_reversing,
+                ) {
+                    Some(frame) => {
+                        // A loop is completed whenever playback wraps back around to the frame it started on
+                        if frame == loop_mode.first_frame(total_frames)
+                            && (frame != self.current_frame || total_frames == 1)
+                        {
+                            self.current_animation_loop_count += 1;
+                        }
+                        self.current_frame = frame;
+                        #[cfg(feature = "callbacks")]
+                        self.callbacks.fire_frame(frame);
+                    }
+                    None => {
+                        // Once loops (and held animations) stay on their last frame, completing the animation the first time it is reached
+                        self.current_animation_loop_time = 0.0;
+                        if !self.animation_completed {
+                            self.animation_completed = true;
+                            #[cfg(feature = "callbacks")]
+                            self.callbacks.fire_animation_complete();
+                        }
+                        break;
+                    }
+                }
+                frame_duration = animation.frame_duration(self.current_frame);
             }
 
             // Check if we've reached the end of the queued duration
@@ -276,7 +864,8 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
             }
         }
 
-        if switch_animation && !self.effects_state.is_active {
+        // Repeating effects never finish, so they don't hold the queue back
+        if switch_animation && (!self.effects_state.is_active || is_repeating_effect) {
             self.animation_queue.pop_front();
             if let Some((next_key, duration)) = self.animation_queue.front() {
                 self.start_new_animation(next_key.clone(), *duration);
@@ -288,6 +877,30 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
 
         self
     }
+    /// Internal, checks if frame progression is currently held by an active Freeze effect, either in the current animation's
+    /// effect or the one applied through `apply_effect_once` (see `AnimationEffect::freezes_frame`).
+    fn _is_frame_frozen(&self) -> bool {
+        let animation_frozen = self.effects_state.is_active
+            && self
+                .animations
+                .get(&self.current_animation_key)
+                .and_then(|animation| animation.effect.as_ref())
+                .is_some_and(|(effect, _, _)| {
+                    effect.freezes_frame(
+                        self.current_frame,
+                        self.effects_state.effect_time,
+                        self.effects_state.current_effect_duration,
+                    )
+                });
+        let override_frozen =
+            self.override_effect
+                .as_ref()
+                .is_some_and(|(effect, duration, elapsed)| {
+                    effect.freezes_frame(self.current_frame, *elapsed, *duration)
+                });
+        animation_frozen || override_frozen
+    }
+
     /// Draws the current frame of the animation on screen using extra params.
     pub fn draw_animation_ex(
         &self,
@@ -295,24 +908,93 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         x_pos: X,
         y_pos: Y,
         color: Color,
+        params: DrawTextureParams,
+    ) {
+        self._draw_animation_clipped(texture, x_pos, y_pos, color, params, None);
+    }
+
+    /// Draws the current frame of the animation on screen with default params, only rendering the part of the sprite
+    /// inside the screen-space `clip` rectangle (ex. a sprite partially hidden behind a wall tile).
+    /// Of note, the clip is applied before effects, so effects which move or scale the sprite move/scale the clipped part.
+    pub fn draw_animation_with_clip_rect(
+        &self,
+        texture: &Texture2D,
+        x_pos: f32,
+        y_pos: f32,
+        color: Color,
+        clip: Rect,
+    ) {
+        self._draw_animation_clipped(
+            texture,
+            x_pos,
+            y_pos,
+            color,
+            DrawTextureParams::default(),
+            Some(clip),
+        );
+    }
+
+    /// Internal, draws the current frame of the animation, optionally clipped to a screen-space rectangle.
+    fn _draw_animation_clipped(
+        &self,
+        texture: &Texture2D,
+        mut x_pos: X,
+        mut y_pos: Y,
+        color: Color,
         mut params: DrawTextureParams,
+        clip: Option<Rect>,
     ) {
         if let Some(animation) = self.animations.get(&self.current_animation_key) {
             if animation.fps == 0 {
                 return; // Don't draw if fps is 0
             }
 
-            let (row, frame, _) = animation.get_row_and_frame_and_fps(self.current_frame);
-            let current_frame_rect = self._get_current_frame_rect(row, frame);
-            params.source = current_frame_rect;
+            let frame_effect = self._get_frame_effect(animation);
+            // The effects drawn below rather than applied, resolved through After and Before with their own progress
+            let drawn_effect = frame_effect
+                .filter(|_| self.effects_state.is_active)
+                .and_then(|effect| {
+                    effect.resolve_at_progress(
+                        self.current_frame,
+                        self._get_effect_progress(animation),
+                    )
+                });
+            if let Some((AnimationEffect::DropFrame(probability), _)) = drawn_effect {
+                if should_drop_frame(*probability) {
+                    return;
+                }
+            }
+
+            params.source = self._get_animation_frame_rect(animation);
+            let dest_size = *params.dest_size.get_or_insert_with(|| {
+                let (width, height) = self._get_animation_draw_size(animation);
+                Vec2::new(width, height)
+            });
+            let (offset_x, offset_y) = self._get_frame_offset(dest_size.x, dest_size.y);
 
-            let mut final_color = color;
-            let mut adjusted_x = x_pos;
-            let mut adjusted_y = y_pos;
+            if let Some(clip) = clip {
+                let Some((clip_x, clip_y)) =
+                    Self::_clip_params(&mut params, x_pos + offset_x, y_pos + offset_y, clip)
+                else {
+                    return;
+                };
+                x_pos += clip_x;
+                y_pos += clip_y;
+            }
+
+            let undistorted_dest_size = params.dest_size;
+            let mut final_color = Color::new(
+                color.r * self.base_color.r,
+                color.g * self.base_color.g,
+                color.b * self.base_color.b,
+                color.a * self.base_color.a,
+            );
+            let mut adjusted_x = x_pos + offset_x;
+            let mut adjusted_y = y_pos + offset_y;
 
-            if let Some((effect, _)) = &animation.effect {
+            if let Some(effect) = frame_effect {
                 if self.effects_state.is_active {
-                    let progress = self.effects_state.progress();
+                    let progress = self._get_effect_progress(animation);
                     effect.apply(
                         progress,
                         &mut final_color,
@@ -325,7 +1007,199 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
                 }
             }
 
-            draw_texture_ex(&texture, adjusted_x, adjusted_y, final_color, params);
+            if let Some((effect, duration, elapsed)) = &self.override_effect {
+                let progress = if *duration > 0.0 {
+                    (elapsed / duration).min(1.0)
+                } else {
+                    1.0
+                };
+                effect.apply(
+                    progress,
+                    &mut final_color,
+                    &mut params,
+                    &mut adjusted_x,
+                    &mut adjusted_y,
+                    self.tile_width,
+                    self.tile_height,
+                );
+            }
+
+            if let Some((AnimationEffect::Ripple(intensity, rings), progress)) = drawn_effect {
+                self._draw_ripple(
+                    texture,
+                    (*intensity, *rings, progress),
+                    adjusted_x,
+                    adjusted_y,
+                    final_color,
+                    &params,
+                );
+            }
+
+            // Silhouette can't be achieved through tinting alone, so it swaps to a material that fills the sprite's shape
+            let active_silhouette_material = match drawn_effect {
+                Some((AnimationEffect::Silhouette(_), _)) => silhouette_material(),
+                _ => None,
+            };
+            if let Some(material) = &active_silhouette_material {
+                gl_use_material(material);
+            }
+
+            draw_texture_ex(texture, adjusted_x, adjusted_y, final_color, params.clone());
+
+            if let Some((AnimationEffect::Mirror(direction), _)) = drawn_effect {
+                self._draw_mirror(
+                    texture,
+                    direction,
+                    adjusted_x,
+                    adjusted_y,
+                    final_color,
+                    params,
+                );
+            }
+
+            if active_silhouette_material.is_some() {
+                gl_use_default_material();
+            }
+
+            if let Some((AnimationEffect::Typewriter(fill_color, columns), progress)) = drawn_effect
+            {
+                let (width, height) = undistorted_dest_size
+                    .map(|size| (size.x, size.y))
+                    .unwrap_or((self.tile_width, self.tile_height));
+                let revealed_width = width * typewriter_revealed_fraction(progress, *columns);
+                let mut fill = fill_color.to_color();
+                fill.a = final_color.a;
+                draw_rectangle(
+                    adjusted_x + revealed_width,
+                    adjusted_y,
+                    width - revealed_width,
+                    height,
+                    fill,
+                );
+            }
+        }
+    }
+
+    /// Internal, shrinks the source and dest size of the params to the part of the sprite drawn at (x_pos, y_pos) inside `clip`.
+    /// Returns how far the top-left of the sprite moved, or None if the sprite is entirely outside of `clip`.
+    fn _clip_params(
+        params: &mut DrawTextureParams,
+        x_pos: X,
+        y_pos: Y,
+        clip: Rect,
+    ) -> Option<(X, Y)> {
+        let dest_size = params.dest_size?;
+        let source = params.source?;
+        let bounds = Rect::new(x_pos, y_pos, dest_size.x, dest_size.y);
+        let visible = bounds.intersect(clip)?;
+        if visible.w <= 0.0 || visible.h <= 0.0 {
+            return None;
+        }
+
+        // Flipped sprites are drawn mirrored, so the clipped side of the source is the opposite one
+        let left = visible.x - bounds.x;
+        let top = visible.y - bounds.y;
+        let source_left = if params.flip_x {
+            bounds.w - left - visible.w
+        } else {
+            left
+        };
+        let source_top = if params.flip_y {
+            bounds.h - top - visible.h
+        } else {
+            top
+        };
+
+        let scale_x = source.w / bounds.w;
+        let scale_y = source.h / bounds.h;
+        params.source = Some(Rect::new(
+            source.x + source_left * scale_x,
+            source.y + source_top * scale_y,
+            visible.w * scale_x,
+            visible.h * scale_y,
+        ));
+        params.dest_size = Some(Vec2::new(visible.w, visible.h));
+        Some((left, top))
+    }
+
+    /// Internal, draws the faded out and flipped copy of the sprite used by the Mirror effect.
+    fn _draw_mirror(
+        &self,
+        texture: &Texture2D,
+        direction: &SlideDirection,
+        x_pos: X,
+        y_pos: Y,
+        color: Color,
+        mut params: DrawTextureParams,
+    ) {
+        let mirror_alpha = 0.4; // Opacity of the mirrored copy relative to the sprite
+        let (width, height) = params
+            .dest_size
+            .map(|size| (size.x, size.y))
+            .unwrap_or((self.tile_width, self.tile_height));
+        let (offset_x, offset_y, flip) = direction.get_mirror_offset(width, height);
+
+        match flip {
+            FlipDirection::Horizontal => params.flip_x = !params.flip_x,
+            FlipDirection::Vertical => params.flip_y = !params.flip_y,
+        }
+
+        let mirror_color = Color::new(color.r, color.g, color.b, color.a * mirror_alpha);
+        draw_texture_ex(
+            texture,
+            x_pos + offset_x,
+            y_pos + offset_y,
+            mirror_color,
+            params,
+        );
+    }
+
+    /// Internal, gets the provided animation's effect which applies on the current frame (see `AnimationEffect::effect_for_frame`).
+    fn _get_frame_effect<'a>(&self, animation: &'a Animation) -> Option<&'a AnimationEffect> {
+        animation
+            .effect
+            .as_ref()
+            .map(|(effect, _, _)| effect.effect_for_frame(self.current_frame))
+    }
+
+    /// Internal, gets the current effect's progress (0.0 to 1.0) after applying the provided animation's easing function.
+    fn _get_effect_progress(&self, animation: &Animation) -> f32 {
+        let progress = self.effects_state.progress();
+        match &animation.effect {
+            Some((_, _, easing)) => easing.apply(progress),
+            None => progress,
+        }
+    }
+
+    /// Internal, draws the rings of the Ripple effect (intensity, rings and progress) behind the sprite, from the outermost ring inwards.
+    fn _draw_ripple(
+        &self,
+        texture: &Texture2D,
+        (intensity, rings, progress): (f32, u32, f32),
+        x_pos: X,
+        y_pos: Y,
+        color: Color,
+        params: &DrawTextureParams,
+    ) {
+        let (width, height) = params
+            .dest_size
+            .map(|size| (size.x, size.y))
+            .unwrap_or((self.tile_width, self.tile_height));
+
+        for ring in (1..=rings).rev() {
+            let (radius, ring_alpha) = ripple_ring(progress, intensity, ring, rings);
+            let ring_params = DrawTextureParams {
+                dest_size: Some(Vec2::new(width + radius * 2.0, height + radius * 2.0)),
+                ..params.clone()
+            };
+            let ring_color = Color::new(color.r, color.g, color.b, color.a * ring_alpha);
+            draw_texture_ex(
+                texture,
+                x_pos - radius,
+                y_pos - radius,
+                ring_color,
+                ring_params,
+            );
         }
     }
 
@@ -340,18 +1214,95 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         dest_size_x: f32,
         dest_size_y: f32,
     ) {
-        let mut draw_params = DrawTextureParams::default();
-        draw_params.dest_size = Some(Vec2::new(dest_size_x, dest_size_y));
+        let draw_params = DrawTextureParams {
+            dest_size: Some(Vec2::new(dest_size_x, dest_size_y)),
+            ..Default::default()
+        };
 
         self.draw_animation_ex(texture, x_pos, y_pos, color, draw_params);
     }
 
-    /// Draws the current frame of the animation on screen with deafault params.
+    /// Same as `draw_animation_dest_sized`, but the sprite is drawn centered on the given position rather than from its top-left.
+    /// This or one of the other draw methods must be continously called by your application.
+    pub fn draw_animation_dest_sized_centered(
+        &self,
+        texture: &Texture2D,
+        center_x: f32,
+        center_y: f32,
+        color: Color,
+        dest_size_x: f32,
+        dest_size_y: f32,
+    ) {
+        self.draw_animation_dest_sized(
+            texture,
+            center_x - dest_size_x / 2.0,
+            center_y - dest_size_y / 2.0,
+            color,
+            dest_size_x,
+            dest_size_y,
+        );
+    }
+
+    /// Draws the current frame of the animation on screen with deafault params.
     /// This or one of the other draw methods must be continously called by your application.
     pub fn draw_animation(&self, texture: &Texture2D, x_pos: f32, y_pos: f32, color: Color) {
         self.draw_animation_ex(texture, x_pos, y_pos, color, DrawTextureParams::default());
     }
 
+    /// Draws the current frame of the animation on screen with default params, using the texture stored via `set_texture`.
+    /// Draws nothing if no texture is stored.
+    pub fn draw_animation_with_stored_texture(&self, x_pos: f32, y_pos: f32, color: Color) {
+        if let Some(texture) = &self.preferred_texture {
+            self.draw_animation(texture, x_pos, y_pos, color);
+        }
+    }
+
+    /// Stores a texture on the sprite for use with `draw_animation_with_stored_texture`, allowing the spritesheet
+    /// to be hot-swapped (ex. equipment skins) without losing queue/effect state.
+    /// Of note, the stored texture is not serialized.
+    pub fn set_texture(&mut self, texture: Texture2D) -> &mut Self {
+        self.preferred_texture = Some(texture);
+        self
+    }
+
+    /// Clears the texture stored via `set_texture`.
+    pub fn clear_texture(&mut self) -> &mut Self {
+        self.preferred_texture = None;
+        self
+    }
+
+    /// Draws the current frame of the animation on screen with deafault params, centered on the given position rather than from its top-left.
+    /// This or one of the other draw methods must be continously called by your application.
+    pub fn draw_animation_centered(
+        &self,
+        texture: &Texture2D,
+        center_x: f32,
+        center_y: f32,
+        color: Color,
+    ) {
+        let (width, height) = self.get_effective_draw_size();
+        self.draw_animation(
+            texture,
+            center_x - width / 2.0,
+            center_y - height / 2.0,
+            color,
+        );
+    }
+
+    /// Draws the current frame of the animation on screen with deafault params, shifted by the given offset.
+    /// This is applied on top of the sprite's stored draw offset (see `set_draw_offset`).
+    pub fn draw_animation_with_offset(
+        &self,
+        texture: &Texture2D,
+        x_pos: f32,
+        y_pos: f32,
+        offset_x: f32,
+        offset_y: f32,
+        color: Color,
+    ) {
+        self.draw_animation(texture, x_pos + offset_x, y_pos + offset_y, color);
+    }
+
     /// Updates the AnimatedSprite<EntityAnimationType>, and calls the default draw method on it back-to-back.
     /// This must be continously called by your application (or one of the other update, and one of the other draw methods).
     pub fn update_and_draw_animation(
@@ -379,9 +1330,110 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         self.draw_animation_ex(texture, x_pos, y_pos, color, params);
     }
 
+    /// Updates the AnimatedSprite<EntityAnimationType>, and calls the dest sized draw method on it back-to-back.
+    /// This must be continously called by your application (or one of the other update, and one of the other draw methods).
+    pub fn update_and_draw_animation_dest_sized(
+        &mut self,
+        texture: &Texture2D,
+        x_pos: f32,
+        y_pos: f32,
+        color: Color,
+        dest_size_x: f32,
+        dest_size_y: f32,
+    ) {
+        self.update();
+        self.draw_animation_dest_sized(texture, x_pos, y_pos, color, dest_size_x, dest_size_y);
+    }
+
+    /// Updates the AnimatedSprite<EntityAnimationType>, and calls the centered draw method on it back-to-back.
+    /// This must be continously called by your application (or one of the other update, and one of the other draw methods).
+    pub fn update_and_draw_animation_centered(
+        &mut self,
+        texture: &Texture2D,
+        center_x: f32,
+        center_y: f32,
+        color: Color,
+    ) {
+        self.update();
+        self.draw_animation_centered(texture, center_x, center_y, color);
+    }
+
+    /// Updates the AnimatedSprite<EntityAnimationType>, and calls the centered dest sized draw method on it back-to-back.
+    /// This must be continously called by your application (or one of the other update, and one of the other draw methods).
+    pub fn update_and_draw_animation_dest_sized_centered(
+        &mut self,
+        texture: &Texture2D,
+        center_x: f32,
+        center_y: f32,
+        color: Color,
+        dest_size_x: f32,
+        dest_size_y: f32,
+    ) {
+        self.update();
+        self.draw_animation_dest_sized_centered(
+            texture,
+            center_x,
+            center_y,
+            color,
+            dest_size_x,
+            dest_size_y,
+        );
+    }
+
+    /// Returns the index of the current frame within the current animation (0 being its first frame).
+    pub fn get_current_frame_index(&self) -> u32 {
+        self.current_frame
+    }
+
+    /// Returns the spritesheet row the current frame is drawn from, or None if there is no current animation.
+    pub fn current_row(&self) -> Option<u32> {
+        self.animations
+            .get(&self.current_animation_key)
+            .map(|animation| animation.get_row_and_frame_and_fps(self.current_frame).0)
+    }
+
+    /// Returns the spritesheet column the current frame is drawn from, or None if there is no current animation.
+    pub fn current_column(&self) -> Option<u32> {
+        self.animations
+            .get(&self.current_animation_key)
+            .map(|animation| animation.get_row_and_frame_and_fps(self.current_frame).1)
+    }
+
+    /// Returns the sub-frame position within the current animation (ex. 2.73 is 73% of the way through frame 2).
+    /// Useful for interpolating between frames or syncing sounds to precise points of an animation.
+    pub fn current_animation_frame_fraction(&self) -> f32 {
+        let animation = match self.animations.get(&self.current_animation_key) {
+            Some(animation) if animation.fps > 0 => animation,
+            _ => return self.current_frame as f32,
+        };
+
+        let frame_duration = animation.frame_duration(self.current_frame);
+        let fraction = (self.current_animation_loop_time / frame_duration).clamp(0.0, 1.0);
+        self.current_frame as f32 + fraction
+    }
+
+    /// Returns the seconds left until the current frame changes (ex. for scheduling sounds or particles on the next frame).
+    /// Returns 0.0 if the current animation has an fps of 0.
+    pub fn get_remaining_frame_time(&self) -> Seconds {
+        match self.animations.get(&self.current_animation_key) {
+            Some(animation) if animation.fps > 0 => (animation.frame_duration(self.current_frame)
+                - self.current_animation_loop_time)
+                .max(0.0),
+            _ => 0.0,
+        }
+    }
+
     /// Gets the current frame rectangle dimensions.
     pub fn get_current_frame_rect(&self) -> Option<Rect> {
         let animation = self.get_current_animation()?;
+        self._get_animation_frame_rect(&animation)
+    }
+
+    /// Internal, gets the current frame rectangle of the provided animation, preferring its explicit rects if it has any.
+    fn _get_animation_frame_rect(&self, animation: &Animation) -> Option<Rect> {
+        if let Some(rect) = animation.get_explicit_rect(self.current_frame) {
+            return Some(rect);
+        }
         let (row, frame, _) = animation.get_row_and_frame_and_fps(self.current_frame);
         self._get_current_frame_rect(row, frame)
     }
@@ -401,12 +1453,86 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
         self.animation_queue.len()
     }
 
+    /// Returns an iterator over the queue's (key, duration) entries, starting with the currently playing one.
+    pub fn iter_queue(&self) -> impl Iterator<Item = (&K, Seconds)> {
+        self.animation_queue
+            .iter()
+            .map(|(key, duration)| (key, *duration))
+    }
+
+    /// Returns an iterator over the queue's (key, duration, remaining duration) entries, starting with the currently playing one.
+    /// Only the first entry has started playing, so every other entry has its full duration remaining.
+    pub fn iter_queue_with_remaining_time(&self) -> impl Iterator<Item = (&K, Seconds, Seconds)> {
+        self.animation_queue
+            .iter()
+            .enumerate()
+            .map(|(index, (key, duration))| {
+                let remaining = if index == 0 {
+                    (duration - self.current_queue_time).max(0.0)
+                } else {
+                    *duration
+                };
+                (key, *duration, remaining)
+            })
+    }
+
     /// Clears the animation queue.
     pub fn clear_queue(&mut self) -> &mut Self {
         self.animation_queue.clear();
         self
     }
 
+    /// Empties the queue into a Vec of (key, duration) entries and switches back to the default animation.
+    /// Useful for saving the queue separately from the sprite, or pausing a sequence to restore later.
+    pub fn drain_queue_into_vec(&mut self) -> Vec<(K, Seconds)> {
+        let entries = self.animation_queue.drain(..).collect();
+        self.start_new_animation(self.default_animation_key.clone(), f32::MAX);
+        entries
+    }
+
+    /// Replaces the queue with the provided (key, duration) entries (ex. from `drain_queue_into_vec`) and starts the first one.
+    /// All keys are checked before anything is changed, so if any isn't registered the queue is left untouched.
+    pub fn restore_queue_from_slice(
+        &mut self,
+        entries: &[(K, Seconds)],
+    ) -> Result<(), UnknownKeyError<K>> {
+        if let Some((key, _)) = entries
+            .iter()
+            .find(|(key, _)| !self.animations.contains_key(key))
+        {
+            return Err(UnknownKeyError { key: key.clone() });
+        }
+
+        self.animation_queue = entries.iter().cloned().collect();
+        match self.animation_queue.front() {
+            Some((key, duration)) => self.start_new_animation(key.clone(), *duration),
+            None => self.start_new_animation(self.default_animation_key.clone(), f32::MAX),
+        }
+        Ok(())
+    }
+
+    /// Captures the sprite's runtime playback state (queue, frame, timers and effect state, including any `apply_effect_once` effect) without its animations,
+    /// as a lighter checkpoint than cloning the whole sprite.
+    pub fn take_snapshot(&self) -> AnimatedSpriteSnapshot<K> {
+        AnimatedSpriteSnapshot {
+            animation_queue: self.animation_queue.clone(),
+            current_frame: self.current_frame,
+            current_animation_loop_time: self.current_animation_loop_time,
+            current_animation_time: self.current_animation_time,
+            current_queue_time: self.current_queue_time,
+            playing_time: self.playing_time,
+            paused: self.paused,
+            effect_paused: self.effect_paused,
+            current_animation_key: self.current_animation_key.clone(),
+            previous_animation_key: self.previous_animation_key.clone(),
+            effects_state: self.effects_state.clone(),
+            ping_pong_reversing: self.ping_pong_reversing,
+            animation_completed: self.animation_completed,
+            current_animation_loop_count: self.current_animation_loop_count,
+            override_effect: self.override_effect.clone(),
+        }
+    }
+
     /// Resets the sprite.
     pub fn reset(&mut self) -> &mut Self {
         self.current_frame = 0;
@@ -417,11 +1543,1161 @@ impl<K: Eq + Hash + Clone> AnimatedSprite<K> {
 
     /// Returns the number of seconds that this sprite has been animating for in total.
     pub fn get_animation_playing_time(&self) -> Seconds {
-        self.playing_time as f32 / 1000.0
+        self.playing_time / 1000.0
     }
 
     /// Returns the number of seconds that this current animation has been playing for.
     pub fn get_current_animation_time(&self) -> Seconds {
-        self.current_animation_time as f32 / 1000.0
+        self.current_animation_time / 1000.0
+    }
+
+    /// Returns the width of each sprite tile on the spritesheet.
+    pub fn get_tile_width(&self) -> f32 {
+        self.tile_width
+    }
+
+    /// Returns the height of each sprite tile on the spritesheet.
+    pub fn get_tile_height(&self) -> f32 {
+        self.tile_height
+    }
+
+    /// Sets an offset which is applied to the position every time the sprite is drawn, useful for spritesheets
+    /// where the content is not aligned within its tiles.
+    pub fn set_draw_offset(&mut self, offset_x: X, offset_y: Y) -> &mut Self {
+        self.draw_offset = (offset_x, offset_y);
+        self
+    }
+
+    /// Returns the offset applied to the position every time the sprite is drawn.
+    pub fn get_draw_offset(&self) -> (X, Y) {
+        self.draw_offset
+    }
+
+    /// Sets the point of the sprite which is drawn at the position passed to the draw methods, as a fraction of the
+    /// current frame's draw size (ex. (0.5, 1.0) for the bottom center, (0.0, 0.0) by default). This is applied on top of
+    /// the draw offset, and follows animations with their own frame size (`tile_size_override` or explicit rects).
+    pub fn set_anchor(&mut self, anchor_x: f32, anchor_y: f32) -> &mut Self {
+        self.anchor = (anchor_x, anchor_y);
+        self
+    }
+
+    /// Returns the point of the sprite which is drawn at the position passed to the draw methods.
+    pub fn get_anchor(&self) -> (f32, f32) {
+        self.anchor
+    }
+
+    /// Internal, gets the offset a frame drawn at the given size is shifted by, combining the draw offset and the anchor.
+    fn _get_frame_offset(&self, width: f32, height: f32) -> (X, Y) {
+        (
+            self.draw_offset.0 - self.anchor.0 * width,
+            self.draw_offset.1 - self.anchor.1 * height,
+        )
+    }
+
+    /// Returns the size (width, height) the current animation is drawn at when no dest size is provided,
+    /// which is its `tile_size_override` if set, otherwise the sprite's tile size, multiplied by the draw scale.
+    pub fn get_effective_draw_size(&self) -> (f32, f32) {
+        self.get_effective_draw_size_for_key(&self.current_animation_key)
+            .unwrap_or((
+                self.tile_width * self.draw_scale,
+                self.tile_height * self.draw_scale,
+            ))
+    }
+
+    /// Returns the size (width, height) the animation registered under `key` is drawn at when no dest size is provided.
+    /// Returns None if no animation is registered under `key`.
+    pub fn get_effective_draw_size_for_key(&self, key: &K) -> Option<(f32, f32)> {
+        let animation = self.animations.get(key)?;
+        Some(self._get_animation_draw_size(animation))
+    }
+
+    /// Internal, gets the size the provided animation is drawn at when no dest size is provided. Animations made from
+    /// explicit rects use the size of their current rect unless they have a `tile_size_override`.
+    fn _get_animation_draw_size(&self, animation: &Animation) -> (f32, f32) {
+        let (width, height) = animation.tile_size_override.unwrap_or_else(|| {
+            match animation.get_explicit_rect(self.current_frame) {
+                Some(rect) => (rect.w, rect.h),
+                None => (self.tile_width, self.tile_height),
+            }
+        });
+        (width * self.draw_scale, height * self.draw_scale)
+    }
+
+    /// Checks if this sprite drawn at (x1, y1) would overlap `other` drawn at (x2, y2), using their effective draw sizes.
+    /// Sprites playing an empty animation draw nothing, so they never overlap. Of note, this is a simple AABB check, not a physics system.
+    pub fn would_overlap<K2: Eq + Hash + Clone>(
+        &self,
+        other: &AnimatedSprite<K2>,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    ) -> bool {
+        match (
+            self._get_draw_bounds(x1, y1),
+            other._get_draw_bounds(x2, y2),
+        ) {
+            (Some(rect1), Some(rect2)) => rect1.overlaps(&rect2),
+            _ => false,
+        }
+    }
+
+    /// Internal, gets the screen-space rectangle the current frame is drawn in at the given position with default params.
+    /// Returns None if nothing would be drawn.
+    fn _get_draw_bounds(&self, x_pos: X, y_pos: Y) -> Option<Rect> {
+        let animation = self.animations.get(&self.current_animation_key)?;
+        if animation.fps == 0 {
+            return None;
+        }
+
+        let (width, height) = self._get_animation_draw_size(animation);
+        let (offset_x, offset_y) = self._get_frame_offset(width, height);
+        Some(Rect::new(x_pos + offset_x, y_pos + offset_y, width, height))
+    }
+
+    /// Sets the layer the sprite is rendered on, for sorting sprites through `sort_sprites_by_layer` (0 by default).
+    pub fn with_render_layer(mut self, layer: i32) -> Self {
+        self.render_layer = layer;
+        self
+    }
+
+    /// Sets the layer the sprite is rendered on, for sorting sprites through `sort_sprites_by_layer` (0 by default).
+    pub fn set_render_layer(&mut self, layer: i32) -> &mut Self {
+        self.render_layer = layer;
+        self
+    }
+
+    /// Returns the layer the sprite is rendered on.
+    pub fn get_render_layer(&self) -> i32 {
+        self.render_layer
+    }
+
+    /// Sets a tint which is multiplied with the color passed to every draw call, meaning drawing with `WHITE` uses the tint as is.
+    /// Useful for colors that are set once (ex. team colors) rather than threaded through every draw call.
+    pub fn set_color_tint(&mut self, color: Color) -> &mut Self {
+        self.base_color = color;
+        self
+    }
+
+    /// Clears the tint set via `set_color_tint`, resetting it to `WHITE`.
+    pub fn clear_color_tint(&mut self) -> &mut Self {
+        self.base_color = WHITE;
+        self
+    }
+
+    /// Returns the tint multiplied with the color passed to every draw call.
+    pub fn get_color_tint(&self) -> Color {
+        self.base_color
+    }
+
+    /// Sets the scale the sprite is drawn at when no dest size is provided, leaving the tile size untouched (ex. for collisions).
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.draw_scale = scale;
+        self
+    }
+
+    /// Sets the scale the sprite is drawn at when no dest size is provided, leaving the tile size untouched (ex. for collisions).
+    pub fn set_scale(&mut self, scale: f32) -> &mut Self {
+        self.draw_scale = scale;
+        self
+    }
+
+    /// Returns the scale the sprite is drawn at when no dest size is provided.
+    pub fn get_scale(&self) -> f32 {
+        self.draw_scale
+    }
+
+    /// Sets the size of each sprite tile on the spritesheet, useful when swapping to a spritesheet with a different frame size.
+    pub fn set_tile_size(&mut self, tile_width: f32, tile_height: f32) -> &mut Self {
+        self.tile_width = tile_width;
+        self.tile_height = tile_height;
+        self
+    }
+}
+
+/// The reserved key the empty animation is registered under by the empty animation helpers
+pub const EMPTY_ANIMATION_KEY: &str = "__empty__";
+
+impl<K: Eq + Hash + Clone + From<&'static str>> AnimatedSprite<K> {
+    /// Internal, registers `Animation::empty()` under the reserved `EMPTY_ANIMATION_KEY` if it isn't already, returning the key.
+    fn _empty_animation_key(&mut self) -> K {
+        let key = K::from(EMPTY_ANIMATION_KEY);
+        self.animations
+            .entry(key.clone())
+            .or_insert_with(Animation::empty);
+        key
+    }
+
+    /// Adds an empty animation to the queue, meaning nothing will be drawn for a `duration` number of seconds.
+    /// Of note, this is available for keys which can be created from a `&str` (ex. `&str` or `String` keys). As `new` can't
+    /// create such a key for every key type, `Animation::empty()` is registered under the reserved `EMPTY_ANIMATION_KEY`
+    /// on first use instead, after which it is listed with the other animations. Don't register your own animation under that key.
+    pub fn queue_empty_for(&mut self, duration: Seconds) -> &mut Self {
+        let key = self._empty_animation_key();
+        self._push_to_queue(key, duration);
+        self
+    }
+
+    /// Sets the default animation to the reserved empty animation, meaning nothing is drawn once the queue finishes.
+    /// Useful for one-off sprites (explosions, pickups, etc.) which should vanish after playing.
+    pub fn set_default_animation_to_empty(&mut self) -> &mut Self {
+        let key = self._empty_animation_key();
+        let _ = self.set_default_animation_result(key);
+        self
+    }
+}
+
+impl<K: Eq + Hash + Clone + Debug> AnimatedSprite<K> {
+    /// Returns a single line summary of the playback state for logging and debugging state machines,
+    /// formatted as `anim=Idle frame=2/4 queue=1 time=0.50 effect=active(0.42)`.
+    /// The effect is one of `none`, `pending`, `active(progress)`, `paused(progress)` or `done`.
+    pub fn debug_state_str(&self) -> String {
+        let animation = self.animations.get(&self.current_animation_key);
+        let total_frames = animation.map_or(0, Animation::total_frames);
+        let progress = self.effects_state.progress();
+        let effect = match animation.and_then(|animation| animation.effect.as_ref()) {
+            None => "none".to_string(),
+            Some(_) if self.effects_state.is_active && self.effect_paused => {
+                format!("paused({:.2})", progress)
+            }
+            Some(_) if self.effects_state.is_active => format!("active({:.2})", progress),
+            Some(_) if self.effects_state.has_played => "done".to_string(),
+            Some(_) => "pending".to_string(),
+        };
+
+        format!(
+            "anim={:?} frame={}/{} queue={} time={:.2} effect={}",
+            self.current_animation_key,
+            self.current_frame,
+            total_frames,
+            self.animation_queue.len(),
+            self.current_animation_time,
+            effect
+        )
+    }
+}
+
+/// Sorts sprites by their render layer from lowest to highest, which is the order to draw them in.
+/// Sprites on the same layer keep their relative order.
+pub fn sort_sprites_by_layer<K: Eq + Hash + Clone>(sprites: &mut [&AnimatedSprite<K>]) {
+    sprites.sort_by_key(|sprite| sprite.render_layer);
+}
+
+/// A checkpoint of an AnimatedSprite's runtime playback state, created through `AnimatedSprite::take_snapshot`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnimatedSpriteSnapshot<K> {
+    animation_queue: VecDeque<AnimationQueueEntry<K>>,
+    current_frame: u32,
+    current_animation_loop_time: f32,
+    current_animation_time: f32,
+    current_queue_time: EffectDuration,
+    playing_time: EffectDuration,
+    paused: bool,
+    effect_paused: bool,
+    current_animation_key: K,
+    previous_animation_key: Option<K>,
+    effects_state: InternalEffectsState,
+    #[serde(default)]
+    ping_pong_reversing: bool,
+    #[serde(default)]
+    animation_completed: bool,
+    #[serde(default)]
+    current_animation_loop_count: u32,
+    #[serde(default)]
+    override_effect: Option<(AnimationEffect, Seconds, Seconds)>,
+}
+
+impl<K: Eq + Hash + Clone> AnimatedSpriteSnapshot<K> {
+    /// Restores the snapshot's playback state onto `target`, which must have animations registered under every key the
+    /// snapshot plays or queues. All keys are checked before anything is changed, so if any isn't registered `target` is left untouched.
+    pub fn restore_to(&self, target: &mut AnimatedSprite<K>) -> Result<(), UnknownKeyError<K>> {
+        if let Some(key) = std::iter::once(&self.current_animation_key)
+            .chain(self.animation_queue.iter().map(|(key, _)| key))
+            .find(|key| !target.animations.contains_key(key))
+        {
+            return Err(UnknownKeyError { key: key.clone() });
+        }
+
+        target.animation_queue = self.animation_queue.clone();
+        target.current_frame = self.current_frame;
+        target.current_animation_loop_time = self.current_animation_loop_time;
+        target.current_animation_time = self.current_animation_time;
+        target.current_queue_time = self.current_queue_time;
+        target.playing_time = self.playing_time;
+        target.paused = self.paused;
+        target.effect_paused = self.effect_paused;
+        target.current_animation_key = self.current_animation_key.clone();
+        target.previous_animation_key = self.previous_animation_key.clone();
+        target.effects_state = self.effects_state.clone();
+        target.ping_pong_reversing = self.ping_pong_reversing;
+        target.animation_completed = self.animation_completed;
+        target.current_animation_loop_count = self.current_animation_loop_count;
+        target.override_effect = self.override_effect.clone();
+        Ok(())
+    }
+}
+
+/// The runtime playback state of an AnimatedSprite, read back out of an exported sprite by `restore_state_from_json`.
+#[cfg(feature = "serialization")]
+#[derive(Deserialize)]
+struct PlaybackState<K> {
+    animation_queue: VecDeque<AnimationQueueEntry<K>>,
+    current_frame: u32,
+    current_animation_loop_time: f32,
+    current_animation_time: f32,
+    current_queue_time: EffectDuration,
+    playing_time: EffectDuration,
+    paused: bool,
+    #[serde(default)]
+    effect_paused: bool,
+    current_animation_key: K,
+    previous_animation_key: Option<K>,
+    effects_state: InternalEffectsState,
+    #[serde(default)]
+    ping_pong_reversing: bool,
+    #[serde(default)]
+    animation_completed: bool,
+    #[serde(default)]
+    current_animation_loop_count: u32,
+    #[serde(default)]
+    override_effect: Option<(AnimationEffect, Seconds, Seconds)>,
+}
+
+#[cfg(feature = "serialization")]
+impl<K: Eq + Hash + Clone + Serialize + serde::de::DeserializeOwned> AnimatedSprite<K> {
+    /// Exports the whole sprite (animations and playback state) to a JSON string, for use in save games or hot-reload tools.
+    /// The sprite is wrapped in an `AnimatedSpriteState` tagged with the current schema version.
+    pub fn export_state_to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&AnimatedSpriteState {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            sprite: self,
+        })
+    }
+
+    /// Restores the playback state (current animation, frame, timers, queue and effect state, including any `apply_effect_once` effect) from JSON created by `export_state_to_json`.
+    /// The registered animations, tile size and draw offset are left untouched, so a freshly rebuilt sprite can resume where the old one left off.
+    /// State exported with an older schema version is migrated first, while newer versions return `IncompatibleSchemaError`.
+    pub fn restore_state_from_json(&mut self, json: &str) -> Result<(), RestoreStateError> {
+        let state = migrations::migrate_to_current(serde_json::from_str(json)?)?;
+        let state: AnimatedSpriteState<PlaybackState<K>> = serde_json::from_value(state)?;
+        let state = state.sprite;
+
+        self.animation_queue = state.animation_queue;
+        self.current_frame = state.current_frame;
+        self.current_animation_loop_time = state.current_animation_loop_time;
+        self.current_animation_time = state.current_animation_time;
+        self.current_queue_time = state.current_queue_time;
+        self.playing_time = state.playing_time;
+        self.paused = state.paused;
+        self.effect_paused = state.effect_paused;
+        self.current_animation_key = state.current_animation_key;
+        self.previous_animation_key = state.previous_animation_key;
+        self.effects_state = state.effects_state;
+        self.ping_pong_reversing = state.ping_pong_reversing;
+        self.animation_completed = state.animation_completed;
+        self.current_animation_loop_count = state.current_animation_loop_count;
+        self.override_effect = state.override_effect;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ron_serialization")]
+impl<K: Eq + Hash + Clone + Serialize + serde::de::DeserializeOwned> AnimatedSprite<K> {
+    /// Serializes the whole sprite (animations and playback state) to a RON string, which is easier to hand-author than JSON.
+    /// Of note, `AnimationEffect::Custom` is skipped by serde, so sprites using unnamed custom effects can't round-trip through RON.
+    pub fn to_ron_string(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Deserializes a sprite from a RON string, such as one created by `to_ron_string` or a hand-authored config file.
+    pub fn from_ron_str(s: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(s)
+    }
+}
+
+impl<K: Eq + Hash + Clone + Default> Default for AnimatedSprite<K> {
+    /// Creates a placeholder AnimatedSprite with 32x32 tiles and an empty default animation under `K::default()`.
+    fn default() -> Self {
+        AnimatedSprite::new(32.0, 32.0, K::default(), Animation::empty())
+    }
+}
+
+/// Prints a compact summary of the playback state, formatted as `AnimatedSprite { animation: "idle", frame: 2/4, queue: 1 }`.
+impl<K: Eq + Hash + Clone + Display> Display for AnimatedSprite<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = self.get_current_animation_key();
+        write!(
+            f,
+            "AnimatedSprite {{ animation: \"{}\", frame: {}/{}, queue: {} }}",
+            key,
+            self.get_current_frame_index(),
+            self.frame_count_for(key).unwrap_or(0),
+            self.get_queue_length()
+        )
+    }
+}
+
+/// Sprites are equal if they have the same animations, configuration and playback state.
+/// Of note, the total `playing_time` and the stored texture are runtime specific, so they aren't compared.
+impl<K: Eq + Hash + Clone> PartialEq for AnimatedSprite<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tile_width == other.tile_width
+            && self.tile_height == other.tile_height
+            && self.animations == other.animations
+            && self.default_animation_key == other.default_animation_key
+            && self.animation_queue == other.animation_queue
+            && self.current_frame == other.current_frame
+            && self.current_animation_loop_time == other.current_animation_loop_time
+            && self.current_animation_time == other.current_animation_time
+            && self.current_queue_time == other.current_queue_time
+            && self.paused == other.paused
+            && self.effect_paused == other.effect_paused
+            && self.current_animation_key == other.current_animation_key
+            && self.previous_animation_key == other.previous_animation_key
+            && self.effects_state == other.effects_state
+            && self.draw_offset == other.draw_offset
+            && self.draw_scale == other.draw_scale
+            && self.anchor == other.anchor
+            && self.base_color == other.base_color
+            && self.render_layer == other.render_layer
+            && self.override_effect == other.override_effect
+            && self.loop_mode == other.loop_mode
+            && self.ping_pong_reversing == other.ping_pong_reversing
+            && self.animation_completed == other.animation_completed
+            && self.current_animation_loop_count == other.current_animation_loop_count
+    }
+}
+
+/// Internal, the draw scale of sprites serialized before it was added.
+fn default_draw_scale() -> f32 {
+    1.0
+}
+
+/// Internal, the tint of sprites serialized before it was added.
+fn default_base_color() -> Color {
+    WHITE
+}
+
+/// Internal, serializes macroquad's Color (which isn't serializable) as an (r, g, b, a) tuple.
+mod color_rgba {
+    use macroquad::color::Color;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        (color.r, color.g, color.b, color.a).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let (r, g, b, a) = <(f32, f32, f32, f32)>::deserialize(deserializer)?;
+        Ok(Color::new(r, g, b, a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "serialization")]
+    use crate::EasingFunction;
+
+    #[test]
+    fn serialization_round_trip_keeps_playback_state() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        sprite
+            .register_animation(
+                "attack".to_string(),
+                Animation::new(1, 6, 12).with_start_effect(AnimationEffect::FadeIn, 1.0),
+            )
+            .register_animation("walk".to_string(), Animation::new(2, 4, 8));
+        sprite
+            .add_animation_to_queue_result("attack".to_string(), 1.5)
+            .unwrap();
+        sprite
+            .add_animation_to_queue_result("walk".to_string(), 0.5)
+            .unwrap();
+        sprite.update_with_dt(0.3);
+
+        let json = serde_json::to_string(&sprite).unwrap();
+        let restored: AnimatedSprite<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, sprite);
+        assert_eq!(sprite.current_frame, 3);
+        assert_eq!(restored.current_frame, sprite.current_frame);
+        assert_eq!(restored.current_animation_key, sprite.current_animation_key);
+        assert_eq!(restored.animation_queue.len(), sprite.animation_queue.len());
+        assert!(sprite.effects_state.is_active);
+        assert_eq!(
+            restored.effects_state.is_active,
+            sprite.effects_state.is_active
+        );
+    }
+
+    #[test]
+    fn deserializing_fills_in_fields_missing_from_older_sprites() {
+        let sprite = AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        let mut json = serde_json::to_value(&sprite).unwrap();
+        for field in ["effect_paused", "draw_offset", "draw_scale", "base_color"] {
+            json.as_object_mut().unwrap().remove(field);
+        }
+
+        let restored: AnimatedSprite<String> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, sprite);
+    }
+
+    #[test]
+    fn invalid_animations_are_only_rejected_when_checked() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 0));
+        sprite.register_animation("walk", Animation::new(1, 4, 0));
+        assert_eq!(sprite.animation_count(), 2);
+
+        assert_eq!(
+            sprite
+                .register_animation_checked("run", Animation::new(2, 4, 0))
+                .err(),
+            Some(AnimationValidationError::ZeroFpsWithFrames)
+        );
+        assert!(sprite
+            .register_animation_checked("run", Animation::new(2, 4, 8))
+            .is_ok());
+
+        let animation = Animation::new(3, 4, 8);
+        assert_eq!(
+            animation.validate_for_texture(128.0, 96.0, 32.0, 32.0),
+            Err(AnimationValidationError::RowOutOfTexture {
+                row: 3,
+                texture_rows: 3
+            })
+        );
+        assert_eq!(
+            animation.validate_for_texture(96.0, 128.0, 32.0, 32.0),
+            Err(AnimationValidationError::ExceedsTexture)
+        );
+        assert!(animation
+            .validate_for_texture(128.0, 128.0, 32.0, 32.0)
+            .is_ok());
+    }
+
+    #[test]
+    fn nested_freeze_holds_frames_while_timers_advance() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 4));
+        sprite.register_animation(
+            "hit",
+            Animation::new(1, 4, 4).with_start_effect(
+                AnimationEffect::After(0.5, Box::new(AnimationEffect::Freeze(0.25))),
+                1.0,
+            ),
+        );
+        sprite.add_animation_to_queue_result("hit", 2.0).unwrap();
+        sprite.update_with_dt(0.4).update_with_dt(0.2);
+        assert_eq!(sprite.get_current_frame_index(), 2);
+
+        for _ in 0..2 {
+            let (loop_time, animation_time, effect_time) = (
+                sprite.current_animation_loop_time,
+                sprite.current_animation_time,
+                sprite.effects_state.effect_time,
+            );
+            sprite.update_with_dt(0.1);
+            assert_eq!(sprite.current_animation_loop_time, loop_time);
+            assert!(sprite.current_animation_time > animation_time);
+            assert!(sprite.effects_state.effect_time > effect_time);
+        }
+        let loop_time = sprite.current_animation_loop_time;
+        sprite.update_with_dt(0.1);
+        assert!(sprite.current_animation_loop_time > loop_time);
+
+        sprite.apply_effect_once(
+            AnimationEffect::CycleFrameEffect(vec![AnimationEffect::Freeze(0.5)]),
+            1.0,
+        );
+        let loop_time = sprite.current_animation_loop_time;
+        sprite.update_with_dt(0.2);
+        assert_eq!(sprite.current_animation_loop_time, loop_time);
+        assert_eq!(sprite.override_effect.as_ref().unwrap().2, 0.2);
+    }
+
+    #[test]
+    fn anchor_follows_the_current_frame_size() {
+        let mut sprite =
+            AnimatedSprite::with_default_animation_builder("idle", Animation::new(0, 4, 8))
+                .default_animation("idle", Animation::new(1, 4, 8))
+                .register(
+                    "slam",
+                    Animation::new(2, 4, 8).with_tile_size_override(64.0, 48.0),
+                )
+                .scale(2.0)
+                .anchor(0.5, 1.0)
+                .draw_offset(0.0, 4.0)
+                .build();
+        assert_eq!(
+            sprite._get_draw_bounds(100.0, 100.0),
+            Some(Rect::new(68.0, 40.0, 64.0, 64.0))
+        );
+
+        sprite.add_animation_to_queue_result("slam", 1.0).unwrap();
+        assert_eq!(
+            sprite._get_draw_bounds(100.0, 100.0),
+            Some(Rect::new(36.0, 8.0, 128.0, 96.0))
+        );
+    }
+
+    #[test]
+    fn reversed_animations_play_frames_backwards() {
+        let sprite = AnimatedSprite::new(32.0, 32.0, "walk".to_string(), Animation::new(2, 4, 10));
+        let mut reversed = sprite.with_all_animations_reversed();
+
+        let mut columns = Vec::new();
+        for _ in 0..5 {
+            columns.push(reversed.get_current_frame_rect().unwrap().x / 32.0);
+            reversed.update_with_dt(0.1);
+        }
+        assert_eq!(columns, vec![3.0, 2.0, 1.0, 0.0, 3.0]);
+        assert_eq!(reversed.current_row(), Some(2));
+        assert_eq!(reversed.current_column(), Some(2));
+    }
+
+    #[test]
+    fn skipped_frames_are_never_played() {
+        let mut sprite = AnimatedSprite::new(
+            32.0,
+            32.0,
+            "walk".to_string(),
+            Animation::new(0, 5, 10).with_frame_skip(vec![1, 3]),
+        );
+        assert_eq!(sprite.get_current_animation().unwrap().total_frames(), 3);
+
+        let mut columns = Vec::new();
+        for _ in 0..4 {
+            columns.push(sprite.get_current_frame_rect().unwrap().x / 32.0);
+            sprite.update_with_dt(0.1);
+        }
+        assert_eq!(columns, vec![0.0, 2.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    fn snapshot_restores_playback_state() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        sprite.register_animation("walk".to_string(), Animation::new(2, 4, 8));
+        sprite
+            .add_animation_to_queue_result("walk".to_string(), 1.0)
+            .unwrap();
+        sprite.update_with_dt(0.3);
+        let snapshot = sprite.take_snapshot();
+        let checkpoint = sprite.clone();
+
+        sprite.update_with_dt(1.2);
+        assert_ne!(sprite, checkpoint);
+        snapshot.restore_to(&mut sprite).unwrap();
+        assert_eq!(sprite, checkpoint);
+
+        let mut missing_walk =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        assert_eq!(
+            snapshot.restore_to(&mut missing_walk),
+            Err(UnknownKeyError {
+                key: "walk".to_string()
+            })
+        );
+        assert_eq!(missing_walk.get_current_animation_key(), "idle");
+    }
+
+    #[test]
+    fn transition_to_waits_for_the_current_frame() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 4));
+        sprite.register_animation("walk".to_string(), Animation::new(2, 4, 8));
+        sprite.update_with_dt(0.1);
+        sprite
+            .transition_to_result("walk".to_string(), 1.0)
+            .unwrap();
+
+        sprite.update_with_dt(0.1);
+        assert_eq!(sprite.get_current_animation_key(), "idle");
+        sprite.update_with_dt(0.1);
+        assert_eq!(sprite.get_current_animation_key(), "walk");
+        assert_eq!(sprite.get_queue_length(), 1);
+        assert_eq!(
+            sprite
+                .transition_to_result("missing".to_string(), 1.0)
+                .map(|_| ()),
+            Err(AnimatedSpriteError::UnknownKey("missing".to_string()))
+        );
+
+        sprite.update_with_dt(1.0);
+        assert_eq!(sprite.get_current_animation_key(), "idle");
+    }
+
+    #[test]
+    fn queue_empty_for_draws_nothing_for_the_duration() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 4));
+        sprite.queue_empty_for(0.5);
+        assert_eq!(sprite.get_current_animation_key(), EMPTY_ANIMATION_KEY);
+        assert_eq!(sprite.get_queue_length(), 1);
+        assert_eq!(
+            sprite.animations.get(EMPTY_ANIMATION_KEY),
+            Some(&Animation::empty())
+        );
+
+        sprite.update_with_dt(0.6);
+        assert_eq!(sprite.get_current_animation_key(), "idle");
+        assert_eq!(sprite.get_queue_length(), 0);
+    }
+
+    #[test]
+    fn is_playing_animation_follows_the_drawn_animation() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 4));
+        sprite.register_animation("walk", Animation::new(1, 4, 4));
+        sprite.add_animation_to_queue_result("walk", 0.5).unwrap();
+        assert!(sprite.is_playing_animation(&"walk"));
+        assert!(!sprite.is_playing_animation(&"idle"));
+
+        sprite.update_with_dt(0.5);
+        assert!(sprite.is_playing_animation(&"idle"));
+        assert!(!sprite.is_animation_queued(&"walk"));
+    }
+
+    #[test]
+    fn transition_to_keeps_the_rest_of_the_queue() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 4));
+        sprite
+            .register_animation("walk", Animation::new(1, 4, 4))
+            .register_animation("jump", Animation::new(2, 4, 8))
+            .register_animation("run", Animation::new(3, 4, 8));
+        sprite
+            .queue_sequence_result(&[("walk", 2.0), ("run", 1.0)])
+            .unwrap();
+        sprite.update_with_dt(0.1);
+        sprite.transition_to_result("jump", 0.5).unwrap();
+
+        let queue: Vec<_> = sprite.iter_queue().map(|(key, _)| *key).collect();
+        assert_eq!(queue, vec!["walk", "jump", "run"]);
+        sprite.update_with_dt(0.15);
+        assert_eq!(*sprite.get_current_animation_key(), "jump");
+        sprite.update_with_dt(0.5);
+        assert_eq!(*sprite.get_current_animation_key(), "run");
+    }
+
+    #[test]
+    fn display_summarizes_playback() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 10));
+        sprite.update_with_dt(0.25);
+        assert_eq!(
+            sprite.to_string(),
+            "AnimatedSprite { animation: \"idle\", frame: 2/4, queue: 0 }"
+        );
+    }
+
+    #[test]
+    fn frame_durations_control_frame_changes() {
+        let mut sprite = AnimatedSprite::new(
+            32.0,
+            32.0,
+            "idle",
+            Animation::new_from_frame_durations(0, vec![0.1, 0.5, 0.2]),
+        );
+        assert_eq!(sprite.frame_count_for(&"idle"), Some(3));
+        assert_eq!(sprite.animation_duration_for(&"idle"), Some(0.8));
+
+        sprite.update_with_dt(0.15);
+        assert_eq!(sprite.get_current_frame_index(), 1);
+        sprite.update_with_dt(0.4);
+        assert_eq!(sprite.get_current_frame_index(), 1);
+        sprite.update_with_dt(0.1);
+        assert_eq!(sprite.get_current_frame_index(), 2);
+    }
+
+    #[test]
+    fn frame_durations_follow_skipped_frames() {
+        let animation =
+            Animation::new_from_frame_durations(0, vec![0.1, 0.5, 0.2]).with_frame_skip(vec![1]);
+        assert_eq!(animation.total_frames(), 2);
+        assert_eq!(animation.frame_duration(1), 0.2);
+        assert_eq!(animation.total_duration_seconds(), 0.3);
+        assert_eq!(animation.reversed().frame_duration(0), 0.2);
+
+        let empty = Animation::new_from_frame_durations(2, Vec::new());
+        assert_eq!(empty.fps, 0);
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", empty);
+        sprite.update_with_dt(1.0);
+        assert_eq!(sprite.get_current_frame_index(), 0);
+    }
+
+    #[test]
+    fn subsample_keeps_the_animation_duration() {
+        let animation = Animation::new(0, 6, 12);
+        let every_frame = animation.subsample(1);
+        assert_eq!(every_frame.total_frames(), 6);
+        assert_eq!(every_frame.fps, 12);
+        assert_eq!(every_frame.frame_durations, None);
+
+        let every_other = animation.subsample(2);
+        assert_eq!(every_other.total_frames(), 3);
+        assert_eq!(every_other.fps, 6);
+        assert_eq!(every_other.get_row_and_frame_and_fps(1), (0, 2, 6));
+        assert_eq!(every_other.total_duration_seconds(), 0.5);
+
+        let uneven = Animation::new(0, 6, 10).subsample(4);
+        assert_eq!(uneven.total_frames(), 2);
+        assert_eq!(uneven.frame_duration(0), 0.4);
+
+        let timed = Animation::new_from_frame_durations(0, vec![0.1, 0.3, 0.2, 0.2]).subsample(2);
+        assert_eq!(timed.frame_duration(0), 0.4);
+        assert_eq!(timed.frame_duration(1), 0.4);
+        assert_eq!(timed.total_duration_seconds(), 0.8);
+
+        assert_eq!(animation.subsample(0), every_frame);
+        assert_eq!(
+            animation.subsample_checked(0),
+            Err(AnimationValidationError::ZeroSubsampleStep)
+        );
+        assert_eq!(animation.subsample_checked(2), Ok(every_other));
+    }
+
+    /// Internal, steps the sprite one frame at a time (at 10 fps), returning the frame index after each step.
+    fn step_frames<K: Eq + Hash + Clone>(sprite: &mut AnimatedSprite<K>, steps: usize) -> Vec<u32> {
+        (0..steps)
+            .map(|_| sprite.update_with_dt(0.1).get_current_frame_index())
+            .collect()
+    }
+
+    #[test]
+    fn loop_modes_advance_frames() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 3, 10));
+        sprite.set_loop_mode(LoopMode::PingPong);
+        assert_eq!(step_frames(&mut sprite, 6), vec![1, 2, 1, 0, 1, 2]);
+
+        assert_eq!(sprite.get_animation_loop_count(), 1);
+
+        sprite.set_frame_result(0).unwrap();
+        sprite.set_loop_mode(LoopMode::Reverse);
+        assert_eq!(step_frames(&mut sprite, 3), vec![2, 1, 0]);
+
+        sprite.set_frame_result(0).unwrap();
+        sprite.set_loop_mode(LoopMode::Once);
+        assert_eq!(step_frames(&mut sprite, 4), vec![1, 2, 2, 2]);
+        assert!(sprite.is_animation_complete());
+    }
+
+    #[test]
+    fn held_animations_stop_on_their_last_frame() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 3, 10));
+        sprite
+            .register_animation("die", Animation::new(1, 3, 10).with_hold_last_frame(true))
+            .set_loop_mode(LoopMode::Reverse);
+        sprite.add_animation_to_queue_result("die", 1.0).unwrap();
+
+        assert_eq!(sprite.get_current_frame_index(), 0);
+        assert_eq!(step_frames(&mut sprite, 4), vec![1, 2, 2, 2]);
+        assert!(sprite.is_animation_complete());
+    }
+
+    #[test]
+    fn snapshot_restores_loop_mode_state() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 3, 10));
+        sprite.set_loop_mode(LoopMode::PingPong);
+        assert_eq!(step_frames(&mut sprite, 3), vec![1, 2, 1]);
+        let reversing = sprite.take_snapshot();
+        assert_eq!(step_frames(&mut sprite, 2), vec![0, 1]);
+        assert_eq!(sprite.get_animation_loop_count(), 1);
+        reversing.restore_to(&mut sprite).unwrap();
+        assert_eq!(sprite.get_animation_loop_count(), 0);
+        assert_eq!(step_frames(&mut sprite, 2), vec![0, 1]);
+
+        sprite.set_frame_result(0).unwrap();
+        sprite.set_loop_mode(LoopMode::Once);
+        let start = sprite.take_snapshot();
+        assert_eq!(step_frames(&mut sprite, 3), vec![1, 2, 2]);
+        assert!(sprite.is_animation_complete());
+        start.restore_to(&mut sprite).unwrap();
+        assert!(!sprite.is_animation_complete());
+        assert_eq!(step_frames(&mut sprite, 3), vec![1, 2, 2]);
+        assert!(sprite.is_animation_complete());
+    }
+
+    #[cfg(feature = "callbacks")]
+    #[test]
+    fn callbacks_keep_sprites_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AnimatedSprite<String>>();
+    }
+
+    #[cfg(feature = "callbacks")]
+    #[test]
+    fn once_loop_fires_animation_complete_callbacks() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Arc;
+
+        let completions = Arc::new(AtomicU32::new(0));
+        let counter = completions.clone();
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 3, 10));
+        sprite
+            .set_loop_mode(LoopMode::Once)
+            .on_animation_complete(Box::new(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }));
+
+        step_frames(&mut sprite, 5);
+        assert_eq!(completions.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "callbacks")]
+    #[test]
+    fn specific_frame_callbacks_fire_in_registration_order() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 3, 10));
+        for name in ["first", "second"] {
+            let events = events.clone();
+            sprite.on_specific_frame(2, Box::new(move || events.lock().unwrap().push(name)));
+        }
+        let changes = events.clone();
+        sprite.on_frame_change(Box::new(move || changes.lock().unwrap().push("change")));
+
+        step_frames(&mut sprite, 1);
+        assert_eq!(*events.lock().unwrap(), vec!["change"]);
+        step_frames(&mut sprite, 3);
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec!["change", "change", "first", "second", "change", "change"]
+        );
+        sprite.clear_frame_change_callbacks();
+        events.lock().unwrap().clear();
+
+        sprite.clear_specific_frame_callbacks(2);
+        step_frames(&mut sprite, 3);
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn debug_state_str_summarizes_playback() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 10));
+        sprite.register_animation(
+            "attack".to_string(),
+            Animation::new(1, 6, 10).with_start_effect(AnimationEffect::FadeIn, 1.0),
+        );
+        assert_eq!(
+            sprite.debug_state_str(),
+            "anim=\"idle\" frame=0/4 queue=0 time=0.00 effect=none"
+        );
+
+        sprite
+            .add_animation_to_queue_result("attack".to_string(), 2.0)
+            .unwrap();
+        sprite.update_with_dt(0.25);
+        assert_eq!(
+            sprite.debug_state_str(),
+            "anim=\"attack\" frame=2/6 queue=1 time=0.25 effect=active(0.25)"
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn restore_state_from_json_keeps_registered_animations() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        sprite.register_animation("walk".to_string(), Animation::new(2, 4, 8));
+        sprite
+            .add_animation_to_queue_result("walk".to_string(), 1.0)
+            .unwrap();
+        sprite.update_with_dt(0.3);
+        let json = sprite.export_state_to_json().unwrap();
+
+        let mut reloaded =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        reloaded.register_animation("walk".to_string(), Animation::new(3, 8, 8));
+        reloaded.restore_state_from_json(&json).unwrap();
+
+        assert_eq!(reloaded.get_current_animation_key(), "walk");
+        assert_eq!(reloaded.current_frame, sprite.current_frame);
+        assert_eq!(reloaded.get_queue_length(), sprite.get_queue_length());
+        assert_eq!(
+            reloaded.get_current_animation(),
+            Some(Animation::new(3, 8, 8))
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn restore_state_from_json_restores_loop_mode_state() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 3, 10));
+        sprite.set_loop_mode(LoopMode::PingPong);
+        step_frames(&mut sprite, 7);
+        assert_eq!(sprite.get_animation_loop_count(), 1);
+        let json = sprite.export_state_to_json().unwrap();
+
+        let mut reloaded =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 3, 10));
+        reloaded.set_loop_mode(LoopMode::PingPong);
+        reloaded.restore_state_from_json(&json).unwrap();
+        assert_eq!(reloaded.get_animation_loop_count(), 1);
+        assert_eq!(step_frames(&mut reloaded, 2), vec![0, 1]);
+
+        sprite.set_loop_mode(LoopMode::Once);
+        step_frames(&mut sprite, 3);
+        assert!(sprite.is_animation_complete());
+        reloaded.set_loop_mode(LoopMode::Once);
+        reloaded
+            .restore_state_from_json(&sprite.export_state_to_json().unwrap())
+            .unwrap();
+        assert!(reloaded.is_animation_complete());
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn restore_state_from_json_restores_override_effect() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 3, 10));
+        sprite.apply_effect_once(AnimationEffect::FadeOut, 1.0);
+        step_frames(&mut sprite, 3);
+        let json = sprite.export_state_to_json().unwrap();
+
+        let mut reloaded =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 3, 10));
+        reloaded.restore_state_from_json(&json).unwrap();
+        let (effect, duration, elapsed) = reloaded.override_effect.clone().unwrap();
+        assert_eq!(effect, AnimationEffect::FadeOut);
+        assert_eq!(duration, 1.0);
+        assert!((elapsed - 0.3).abs() < 1e-5);
+
+        let snapshot = reloaded.take_snapshot();
+        step_frames(&mut reloaded, 8);
+        assert!(reloaded.override_effect.is_none());
+        snapshot.restore_to(&mut reloaded).unwrap();
+        assert_eq!(reloaded.override_effect, sprite.override_effect);
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn restore_state_from_json_checks_schema_version() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        sprite.register_animation("walk".to_string(), Animation::new(2, 4, 8));
+        sprite
+            .add_animation_to_queue_result("walk".to_string(), 1.0)
+            .unwrap();
+
+        // Unversioned exports from before schema versioning are migrated
+        let unversioned = serde_json::to_string(&sprite).unwrap();
+        let mut reloaded =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        reloaded.restore_state_from_json(&unversioned).unwrap();
+        assert_eq!(reloaded.get_queue_length(), 1);
+
+        // Version 1 effects had no easing function, which the migration fills in as Linear
+        let v1 = r#"{"schema_version":1,"sprite":{"animations":{"fade":{"rows":[0],"frames_per_row":4,"fps":6,"effect":["FadeIn",{"Start":1.0}]}}}}"#;
+        let migrated = migrations::migrate_to_current(serde_json::from_str(v1).unwrap()).unwrap();
+        assert_eq!(migrated["schema_version"], CURRENT_SCHEMA_VERSION);
+        let animation: Animation =
+            serde_json::from_value(migrated["sprite"]["animations"]["fade"].clone()).unwrap();
+        assert_eq!(
+            animation.effect,
+            Some((
+                AnimationEffect::FadeIn,
+                EffectTimeTarget::Start(1.0),
+                EasingFunction::Linear
+            ))
+        );
+
+        let newer = format!(
+            r#"{{"schema_version":{},"sprite":{}}}"#,
+            CURRENT_SCHEMA_VERSION + 1,
+            unversioned
+        );
+        assert!(matches!(
+            reloaded.restore_state_from_json(&newer),
+            Err(RestoreStateError::IncompatibleSchema(_))
+        ));
+    }
+
+    #[cfg(feature = "ron_serialization")]
+    #[test]
+    fn ron_round_trip_keeps_playback_state() {
+        let mut sprite =
+            AnimatedSprite::new(32.0, 32.0, "idle".to_string(), Animation::new(0, 4, 6));
+        sprite.register_animation(
+            "attack".to_string(),
+            Animation::new(1, 6, 12)
+                .with_start_effect(AnimationEffect::Mirror(SlideDirection::Left), 1.0),
+        );
+        sprite
+            .add_animation_to_queue_result("attack".to_string(), 1.5)
+            .unwrap();
+        sprite.update_with_dt(0.3);
+
+        let ron = sprite.to_ron_string().unwrap();
+        let restored: AnimatedSprite<String> = AnimatedSprite::from_ron_str(&ron).unwrap();
+
+        assert_eq!(restored, sprite);
+        assert_eq!(restored.current_frame, sprite.current_frame);
+        assert_eq!(restored.current_animation_key, sprite.current_animation_key);
+        assert_eq!(restored.animation_count(), sprite.animation_count());
+    }
+
+    #[test]
+    fn drop_frame_at_full_probability_draws_nothing() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 6));
+        sprite.register_animation(
+            "stutter",
+            Animation::new(1, 4, 6).with_start_effect(AnimationEffect::DropFrame(1.0), 1.0),
+        );
+        sprite
+            .add_animation_to_queue_result("stutter", 1.0)
+            .unwrap();
+        sprite.update_with_dt(0.3);
+
+        // There is no graphics context in tests, so any attempt to actually draw would panic
+        let texture = Texture2D::from_miniquad_texture(
+            macroquad::miniquad::TextureId::from_raw_id(macroquad::miniquad::RawId::OpenGl(0)),
+        );
+        sprite.draw_animation(&texture, 10.0, 10.0, Color::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn drop_frame_nested_in_before_draws_nothing_until_its_threshold() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 6));
+        sprite.register_animation(
+            "stutter",
+            Animation::new(1, 4, 6).with_start_effect(
+                AnimationEffect::Before(0.5, Box::new(AnimationEffect::DropFrame(1.0))),
+                1.0,
+            ),
+        );
+        sprite
+            .add_animation_to_queue_result("stutter", 1.0)
+            .unwrap();
+        sprite.update_with_dt(0.3);
+
+        // There is no graphics context in tests, so this would panic if the frame was drawn
+        let texture = Texture2D::from_miniquad_texture(
+            macroquad::miniquad::TextureId::from_raw_id(macroquad::miniquad::RawId::OpenGl(0)),
+        );
+        sprite.draw_animation(&texture, 10.0, 10.0, Color::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn drop_frame_nested_in_after_draws_nothing_past_its_threshold() {
+        let mut sprite = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 6));
+        sprite.register_animation(
+            "stutter",
+            Animation::new(1, 4, 6).with_start_effect(
+                AnimationEffect::After(0.5, Box::new(AnimationEffect::DropFrame(1.0))),
+                1.0,
+            ),
+        );
+        sprite
+            .add_animation_to_queue_result("stutter", 1.0)
+            .unwrap();
+        sprite.update_with_dt(0.6);
+
+        // There is no graphics context in tests, so this would panic if the frame was drawn
+        let texture = Texture2D::from_miniquad_texture(
+            macroquad::miniquad::TextureId::from_raw_id(macroquad::miniquad::RawId::OpenGl(0)),
+        );
+        sprite.draw_animation(&texture, 10.0, 10.0, Color::new(1.0, 1.0, 1.0, 1.0));
     }
 }
@@ -1,25 +1,56 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-use crate::{AnimationEffect, EffectTimeTarget, Seconds};
+use crate::{
+    AnimationEffect, AnimationValidationError, EasingFunction, EffectTimeTarget, Seconds, X, Y,
+};
+use macroquad::logging::warn;
+use macroquad::math::Rect;
+
+/// The shortest duration a frame created through `Animation::new_from_frame_durations` is shown for.
+const MIN_FRAME_DURATION: Seconds = 0.001;
 
 /// Represents one of the animations part of the spritesheet used by the AnimatedSprite.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Animation {
     pub rows: Vec<u32>,
     pub frames_per_row: u32,
     pub fps: u32,
-    pub effect: Option<(AnimationEffect, EffectTimeTarget)>,
+    pub effect: Option<(AnimationEffect, EffectTimeTarget, EasingFunction)>,
+    /// Source rectangles (x, y, width, height) for each frame, used instead of the tile grid when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explicit_rects: Option<Vec<(X, Y, f32, f32)>>,
+    /// Size (width, height) to draw the animation's frames at instead of the sprite's tile size, when no dest size is provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tile_size_override: Option<(f32, f32)>,
+    /// Order to play the frames in (as indexes into the animation's frames), used instead of playing them in order when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame_order: Option<Vec<u32>>,
+    /// Frames (as indexes into the animation's frames) which are never played, such as placeholder or separator frames.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_frames: Vec<u32>,
+    /// Seconds each frame (as indexes into the animation's frames) is shown for, used instead of the uniform `1.0 / fps` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame_durations: Option<Vec<Seconds>>,
+    /// Stops on the last frame instead of looping back to the first one (ex. death or door opening animations).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hold_last_frame: bool,
 }
 
 impl Animation {
     /// Create a new Animation from a single row on the spritesheet.
     pub fn new(row: u32, frames: u32, fps: u32) -> Self {
         Animation {
-            rows: vec![row.max(0)],
+            rows: vec![row],
             frames_per_row: frames.max(1),
-            fps: fps.max(0),
+            fps,
             effect: None,
+            explicit_rects: None,
+            tile_size_override: None,
+            frame_order: None,
+            skipped_frames: Vec::new(),
+            frame_durations: None,
+            hold_last_frame: false,
         }
     }
 
@@ -28,23 +59,248 @@ impl Animation {
         Animation {
             rows: if rows.is_empty() { vec![1] } else { rows },
             frames_per_row: frames_per_row.max(1),
-            fps: fps.max(0),
+            fps,
             effect: None,
+            explicit_rects: None,
+            tile_size_override: None,
+            frame_order: None,
+            skipped_frames: Vec::new(),
+            frame_durations: None,
+            hold_last_frame: false,
+        }
+    }
+
+    /// Create a new Animation from a single row on the spritesheet, where each frame is shown for its own duration in seconds
+    /// (ex. from tools which export per-frame timing). Of note, durations are clamped to at least 1ms, and `fps` is set to
+    /// the nearest whole fps of the average duration. With no durations the animation has no frames and an fps of 0.
+    pub fn new_from_frame_durations(row: u32, frame_durations: Vec<Seconds>) -> Self {
+        if frame_durations.is_empty() {
+            return Animation::new(row, 0, 0);
+        }
+
+        let frame_durations: Vec<Seconds> = frame_durations
+            .into_iter()
+            .map(|duration| duration.max(MIN_FRAME_DURATION))
+            .collect();
+        let average_duration =
+            frame_durations.iter().sum::<Seconds>() / frame_durations.len() as f32;
+        let fps = (1.0 / average_duration).round().max(1.0) as u32;
+
+        let mut animation = Animation::new(row, frame_durations.len() as u32, fps);
+        animation.frame_durations = Some(frame_durations);
+        animation
+    }
+
+    /// Create a new Animation from explicit source rectangles on the spritesheet, played in the order provided.
+    /// Useful for packed atlases where frames are not laid out on a uniform tile grid.
+    pub fn new_from_explicit_rects(rects: Vec<Rect>, fps: u32) -> Self {
+        let mut animation = Animation::new(0, rects.len() as u32, fps);
+        animation.explicit_rects = Some(
+            rects
+                .into_iter()
+                .map(|rect| (rect.x, rect.y, rect.w, rect.h))
+                .collect(),
+        );
+        animation
+    }
+
+    /// Create a new single row Animation, alongside the duration to queue it for which plays a whole number of loops.
+    /// The duration is the multiple of the animation's length which is nearest to `desired_duration` (at least one loop),
+    /// which avoids the animation being cut off mid-loop.
+    pub fn looped_for_duration(
+        row: u32,
+        frames: u32,
+        fps: u32,
+        desired_duration: Seconds,
+    ) -> (Self, Seconds) {
+        let animation = Animation::new(row, frames, fps);
+        let loop_duration = animation.total_duration_seconds();
+        if loop_duration <= 0.0 {
+            return (animation, desired_duration);
         }
+
+        let loops = (desired_duration / loop_duration).round().max(1.0);
+        (animation, loops * loop_duration)
     }
 
     /// Add a start animation effect that begins at the start of the animation. Duration is represented in seconds from the start.
-    pub fn with_start_effect(mut self, effect: AnimationEffect, duration: Seconds) -> Self {
-        self.effect = Some((effect, EffectTimeTarget::Start(duration)));
-        self
+    pub fn with_start_effect(self, effect: AnimationEffect, duration: Seconds) -> Self {
+        self.with_start_effect_eased(effect, duration, EasingFunction::Linear)
     }
 
     /// Add an end animation effect that ends with the animation.  Duration is represented in seconds from the end.
-    pub fn with_end_effect(mut self, effect: AnimationEffect, duration: Seconds) -> Self {
-        self.effect = Some((effect, EffectTimeTarget::End(duration)));
+    pub fn with_end_effect(self, effect: AnimationEffect, duration: Seconds) -> Self {
+        self.with_end_effect_eased(effect, duration, EasingFunction::Linear)
+    }
+
+    /// Add an animation effect that plays for `duration` seconds at the start of every `period` seconds, for as long as the animation plays.
+    /// Of note, `validate` reports a `duration` longer than the `period`, as each activation must fit within one period.
+    pub fn with_effect_loop(
+        mut self,
+        effect: AnimationEffect,
+        period: Seconds,
+        duration: Seconds,
+    ) -> Self {
+        self.effect = Some((
+            effect,
+            EffectTimeTarget::Repeating { period, duration },
+            EasingFunction::Linear,
+        ));
+        self
+    }
+
+    /// Same as `with_start_effect`, but the effect's progress is remapped through the given easing function.
+    pub fn with_start_effect_eased(
+        mut self,
+        effect: AnimationEffect,
+        duration: Seconds,
+        easing: EasingFunction,
+    ) -> Self {
+        self.effect = Some((effect, EffectTimeTarget::Start(duration), easing));
+        self
+    }
+
+    /// Same as `with_end_effect`, but the effect's progress is remapped through the given easing function.
+    pub fn with_end_effect_eased(
+        mut self,
+        effect: AnimationEffect,
+        duration: Seconds,
+        easing: EasingFunction,
+    ) -> Self {
+        self.effect = Some((effect, EffectTimeTarget::End(duration), easing));
+        self
+    }
+
+    /// Stops the animation on its last frame instead of looping back to the first one, playing its frames forward once
+    /// regardless of the sprite's loop mode. The queued duration still ends the animation as normal.
+    pub fn with_hold_last_frame(mut self, hold: bool) -> Self {
+        self.hold_last_frame = hold;
+        self
+    }
+
+    /// Draws the animation's frames at the given size instead of the sprite's tile size (ex. for a larger attack animation).
+    pub fn with_tile_size_override(mut self, width: f32, height: f32) -> Self {
+        self.tile_size_override = Some((width, height));
         self
     }
 
+    /// Skips the frames at the given indexes (ex. placeholder or separator frames on the spritesheet) when playing the animation.
+    /// Of note, `validate` reports skipped frames which are out of range, or which would leave the animation with no frames.
+    pub fn with_frame_skip(mut self, skip: Vec<u32>) -> Self {
+        self.skipped_frames = skip;
+        self
+    }
+
+    /// Returns a copy of the animation which plays its frames in reverse order.
+    pub fn reversed(&self) -> Self {
+        let mut animation = self.clone();
+        let mut frame_order = self._frame_order();
+        frame_order.reverse();
+        animation.frame_order = Some(frame_order);
+        animation
+    }
+
+    /// Returns a copy of the animation which only plays every `every_nth` frame, for a lower fps look without a separate animation.
+    /// Each kept frame is shown for as long as the frames it replaces, so the animation still takes as long to play through.
+    /// An `every_nth` of 1 plays every frame, and 0 is treated as 1 (logging a warning in debug builds), see `subsample_checked`.
+    pub fn subsample(&self, every_nth: u32) -> Self {
+        if every_nth == 0 && cfg!(debug_assertions) {
+            warn!("subsample was given a step of 0, every frame is played instead");
+        }
+        self._subsample(every_nth.max(1))
+    }
+
+    /// Same as `subsample`, but returns `AnimationValidationError::ZeroSubsampleStep` if `every_nth` is 0.
+    pub fn subsample_checked(&self, every_nth: u32) -> Result<Self, AnimationValidationError> {
+        if every_nth == 0 {
+            return Err(AnimationValidationError::ZeroSubsampleStep);
+        }
+        Ok(self._subsample(every_nth))
+    }
+
+    /// Internal, keeps every `every_nth` playable frame, stretching the kept frames over the frames left out.
+    fn _subsample(&self, every_nth: u32) -> Self {
+        let mut animation = self.clone();
+        let playable_frames: Vec<u32> = self._playable_frames().collect();
+        let frame_order: Vec<u32> = playable_frames
+            .chunks(every_nth as usize)
+            .map(|frames| frames[0])
+            .collect();
+        animation.skipped_frames = Vec::new();
+        if every_nth == 1 {
+            animation.frame_order = Some(frame_order);
+            return animation;
+        }
+
+        match &self.frame_durations {
+            Some(durations) if !durations.is_empty() => {
+                let mut stretched = durations.clone();
+                for frames in playable_frames.chunks(every_nth as usize) {
+                    stretched[frames[0] as usize % durations.len()] = frames
+                        .iter()
+                        .map(|frame| durations[*frame as usize % durations.len()])
+                        .sum();
+                }
+                animation.frame_durations = Some(stretched);
+            }
+            _ if !self.fps.is_multiple_of(every_nth) => {
+                animation.frame_durations = Some(vec![
+                    every_nth as f32 / self.fps as f32;
+                    self._layout_frames() as usize
+                ]);
+            }
+            _ => {}
+        }
+        if self.fps > 0 {
+            animation.fps = ((self.fps as f32 / every_nth as f32).round() as u32).max(1);
+        }
+        animation.frame_order = Some(frame_order);
+        animation
+    }
+
+    /// Internal, gets the order the frames are played in, defaulting to every frame in order.
+    fn _frame_order(&self) -> Vec<u32> {
+        self.frame_order
+            .clone()
+            .unwrap_or_else(|| (0..self._layout_frames()).collect())
+    }
+
+    /// Internal, maps the frame being played to the index of the frame in the spritesheet layout,
+    /// following `frame_order` and leaving out `skipped_frames`.
+    fn _layout_frame(&self, current_frame: u32) -> u32 {
+        if self.skipped_frames.is_empty() {
+            return match &self.frame_order {
+                Some(order) if !order.is_empty() => order[current_frame as usize % order.len()],
+                _ => current_frame,
+            };
+        }
+
+        let playable_frames = self._playable_frames().count();
+        if playable_frames == 0 {
+            return current_frame;
+        }
+        self._playable_frames()
+            .nth(current_frame as usize % playable_frames)
+            .unwrap_or(current_frame)
+    }
+
+    /// Internal, iterates over the layout indexes of the frames which are played, in the order they are played.
+    fn _playable_frames(&self) -> impl Iterator<Item = u32> + '_ {
+        let frames: Box<dyn Iterator<Item = u32>> = match &self.frame_order {
+            Some(order) if !order.is_empty() => Box::new(order.iter().copied()),
+            _ => Box::new(0..self._layout_frames()),
+        };
+        frames.filter(|frame| !self.skipped_frames.contains(frame))
+    }
+
+    /// Internal, gets the number of frames laid out on the spritesheet, regardless of `frame_order`.
+    fn _layout_frames(&self) -> u32 {
+        match &self.explicit_rects {
+            Some(rects) => rects.len() as u32,
+            None => self.rows.len() as u32 * self.frames_per_row,
+        }
+    }
+
     /// Returns an empty Animation with row/frames/fps set to 0.
     /// This means this animation will draw nothing no matter what texture is provided to AnimatedSprite draw methods.
     /// Can be used in between other animations in the queue, or even set as default animation to guarantee nothing is drawn when queue is finished.
@@ -59,14 +315,144 @@ impl Animation {
             return (0, 0, 0);
         }
 
-        let adjusted_frame = current_frame % total_frames;
+        let adjusted_frame = self._layout_frame(current_frame) % total_frames;
         let row_index = (adjusted_frame / self.frames_per_row) as usize;
         let frame = adjusted_frame % self.frames_per_row;
         (self.rows[row_index], frame, self.fps)
     }
 
-    /// Returns the total number of frames in the animation, accounting for all rows.
+    /// Returns the explicit source rectangle for the current frame, if the animation was created from explicit rects.
+    pub fn get_explicit_rect(&self, current_frame: u32) -> Option<Rect> {
+        let rects = self.explicit_rects.as_ref()?;
+        if rects.is_empty() {
+            return None;
+        }
+
+        let (x, y, w, h) = rects[self._layout_frame(current_frame) as usize % rects.len()];
+        Some(Rect::new(x, y, w, h))
+    }
+
+    /// Checks the animation for configurations which would silently misbehave when played.
+    /// Of note, `Animation::empty()` is valid, as its single frame never needs to advance.
+    pub fn validate(&self) -> Result<(), AnimationValidationError> {
+        if self.rows.is_empty() {
+            return Err(AnimationValidationError::EmptyRows);
+        }
+        if self.frames_per_row == 0 && self.fps > 0 {
+            return Err(AnimationValidationError::FramesPerRowIsZero);
+        }
+        if let Some(&frame) = self
+            .skipped_frames
+            .iter()
+            .find(|&&frame| frame >= self._layout_frames())
+        {
+            return Err(AnimationValidationError::SkippedFrameOutOfRange {
+                frame,
+                total_frames: self._layout_frames(),
+            });
+        }
+        if !self.skipped_frames.is_empty() && self.total_frames() == 0 {
+            return Err(AnimationValidationError::AllFramesSkipped);
+        }
+        if let Some((_, EffectTimeTarget::Repeating { period, duration }, _)) = &self.effect {
+            if duration > period {
+                return Err(AnimationValidationError::EffectLongerThanPeriod);
+            }
+        }
+        if self.fps == 0 && self.total_frames() > 1 {
+            return Err(AnimationValidationError::ZeroFpsWithFrames);
+        }
+        Ok(())
+    }
+
+    /// Same as `validate`, but also checks the animation against the texture it will be drawn from (ex. once the texture is loaded),
+    /// reporting rows past the texture's last row of tiles and frames which would be read from outside of it.
+    pub fn validate_for_texture(
+        &self,
+        texture_width: f32,
+        texture_height: f32,
+        tile_width: f32,
+        tile_height: f32,
+    ) -> Result<(), AnimationValidationError> {
+        self.validate()?;
+        if self.explicit_rects.is_none() && tile_height > 0.0 {
+            let texture_rows = (texture_height / tile_height) as u32;
+            if let Some(&row) = self.rows.iter().find(|&&row| row >= texture_rows) {
+                return Err(AnimationValidationError::RowOutOfTexture { row, texture_rows });
+            }
+        }
+        if self.would_exceed_texture(texture_width, texture_height, tile_width, tile_height) {
+            return Err(AnimationValidationError::ExceedsTexture);
+        }
+        Ok(())
+    }
+
+    /// Checks if any of the animation's frames would be read from outside of a texture of the given size,
+    /// using the given tile size (or the explicit rects if the animation was created from them).
+    pub fn would_exceed_texture(
+        &self,
+        texture_width: f32,
+        texture_height: f32,
+        tile_width: f32,
+        tile_height: f32,
+    ) -> bool {
+        let (width, height) = self.expected_texture_size(tile_width, tile_height);
+        width > texture_width || height > texture_height
+    }
+
+    /// Returns the minimum (width, height) a texture needs for every frame of this animation to fit within it,
+    /// which can be checked against a loaded texture's `width()` and `height()`.
+    /// Explicit rects are measured by their furthest edges instead of the tile grid.
+    pub fn expected_texture_size(&self, tile_width: f32, tile_height: f32) -> (f32, f32) {
+        if let Some(rects) = &self.explicit_rects {
+            return rects
+                .iter()
+                .fold((0.0, 0.0), |(width, height), (x, y, w, h)| {
+                    (f32::max(width, x + w), f32::max(height, y + h))
+                });
+        }
+
+        let max_row = self.rows.iter().copied().max().unwrap_or(0);
+        (
+            self.frames_per_row as f32 * tile_width,
+            (max_row + 1) as f32 * tile_height,
+        )
+    }
+
+    /// Returns the seconds the given frame is shown for, which is `1.0 / fps` unless the animation has per-frame durations.
+    /// Durations follow the frames they were set for, so they stay matched up through `reversed`, `subsample` and skipped frames.
+    pub fn frame_duration(&self, frame: u32) -> Seconds {
+        match &self.frame_durations {
+            Some(durations) if !durations.is_empty() => {
+                let layout_frame = self._layout_frame(frame) as usize;
+                durations[layout_frame % durations.len()].max(MIN_FRAME_DURATION)
+            }
+            _ => 1.0 / self.fps as f32,
+        }
+    }
+
+    /// Returns the total number of frames in the animation, accounting for all rows (and the `frame_order`/`skipped_frames` if set).
+    /// Of note, `frame_durations` never changes the frame count, so skipped frames are left out even when durations are set.
     pub fn total_frames(&self) -> u32 {
-        self.rows.len() as u32 * self.frames_per_row
+        if !self.skipped_frames.is_empty() {
+            return self._playable_frames().count() as u32;
+        }
+        match &self.frame_order {
+            Some(order) if !order.is_empty() => order.len() as u32,
+            _ => self.rows.len() as u32 * self.frames_per_row,
+        }
+    }
+
+    /// Returns the number of seconds it takes to play through all frames of the animation once (0.0 if fps is 0).
+    pub fn total_duration_seconds(&self) -> Seconds {
+        if self.fps == 0 {
+            return 0.0;
+        }
+        if self.frame_durations.is_some() {
+            return (0..self.total_frames())
+                .map(|frame| self.frame_duration(frame))
+                .sum();
+        }
+        self.total_frames() as f32 / self.fps as f32
     }
 }
@@ -0,0 +1,85 @@
+use std::hash::Hash;
+
+use crate::{AnimatedSprite, Animation, X, Y};
+
+/// A fluent builder for configuring an AnimatedSprite, created through `AnimatedSprite::with_default_animation_builder`.
+pub struct AnimatedSpriteBuilder<K: Eq + Hash + Clone> {
+    tile_width: f32,
+    tile_height: f32,
+    default_animation: (K, Animation),
+    animations: Vec<(K, Animation)>,
+    anchor: (f32, f32),
+    scale: f32,
+    draw_offset: (X, Y),
+}
+
+impl<K: Eq + Hash + Clone> AnimatedSpriteBuilder<K> {
+    /// Creates a new builder with 32x32 tiles and the given default animation.
+    pub fn new(default_animation_key: K, default_animation: Animation) -> Self {
+        AnimatedSpriteBuilder {
+            tile_width: 32.0,
+            tile_height: 32.0,
+            default_animation: (default_animation_key, default_animation),
+            animations: Vec::new(),
+            anchor: (0.0, 0.0),
+            scale: 1.0,
+            draw_offset: (0.0, 0.0),
+        }
+    }
+
+    /// Sets the size of a single tile on the spritesheet.
+    pub fn tile_size(mut self, tile_width: f32, tile_height: f32) -> Self {
+        self.tile_width = tile_width;
+        self.tile_height = tile_height;
+        self
+    }
+
+    /// Sets the default animation, replacing the one the builder was created with.
+    pub fn default_animation(mut self, key: K, animation: Animation) -> Self {
+        self.default_animation = (key, animation);
+        self
+    }
+
+    /// Registers an animation in the sprite (see `AnimatedSprite::register_animation`).
+    pub fn register(mut self, key: K, animation: Animation) -> Self {
+        self.animations.push((key, animation));
+        self
+    }
+
+    /// Sets the point of the sprite which is drawn at the position passed to the draw methods (see `AnimatedSprite::set_anchor`).
+    pub fn anchor(mut self, anchor_x: f32, anchor_y: f32) -> Self {
+        self.anchor = (anchor_x, anchor_y);
+        self
+    }
+
+    /// Sets the scale the sprite is drawn at (see `AnimatedSprite::set_scale`).
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the offset the sprite is drawn at (see `AnimatedSprite::set_draw_offset`).
+    pub fn draw_offset(mut self, offset_x: X, offset_y: Y) -> Self {
+        self.draw_offset = (offset_x, offset_y);
+        self
+    }
+
+    /// Builds the configured AnimatedSprite.
+    pub fn build(self) -> AnimatedSprite<K> {
+        let (default_key, default_animation) = self.default_animation;
+        let mut sprite = AnimatedSprite::new(
+            self.tile_width,
+            self.tile_height,
+            default_key,
+            default_animation,
+        );
+        for (key, animation) in self.animations {
+            sprite.register_animation(key, animation);
+        }
+        sprite
+            .set_scale(self.scale)
+            .set_draw_offset(self.draw_offset.0, self.draw_offset.1)
+            .set_anchor(self.anchor.0, self.anchor.1);
+        sprite
+    }
+}
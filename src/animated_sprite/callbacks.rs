@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+
+/// A callback registered on an AnimatedSprite, which is `Send + Sync` so sprites can still be shared across threads
+pub type SpriteCallback = Box<dyn FnMut() + Send + Sync>;
+
+/// The callbacks registered on an AnimatedSprite. Closures can't be cloned or serialized, so cloned and
+/// deserialized sprites start without any callbacks.
+#[derive(Default)]
+pub(crate) struct SpriteCallbacks {
+    pub(crate) on_complete_callbacks: Vec<SpriteCallback>,
+    pub(crate) frame_change_callbacks: Vec<SpriteCallback>,
+    pub(crate) frame_event_map: HashMap<u32, Vec<SpriteCallback>>,
+}
+
+impl SpriteCallbacks {
+    /// Calls every animation complete callback in registration order.
+    pub(crate) fn fire_animation_complete(&mut self) {
+        for callback in &mut self.on_complete_callbacks {
+            callback();
+        }
+    }
+
+    /// Calls every frame change callback, then every callback registered for the given frame index, in registration order.
+    pub(crate) fn fire_frame(&mut self, frame: u32) {
+        for callback in &mut self.frame_change_callbacks {
+            callback();
+        }
+        if let Some(callbacks) = self.frame_event_map.get_mut(&frame) {
+            for callback in callbacks {
+                callback();
+            }
+        }
+    }
+}
+
+impl Clone for SpriteCallbacks {
+    fn clone(&self) -> Self {
+        SpriteCallbacks::default()
+    }
+}
+
+impl Debug for SpriteCallbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SpriteCallbacks {{ on_complete: {}, frame_changes: {}, frame_events: {} }}",
+            self.on_complete_callbacks.len(),
+            self.frame_change_callbacks.len(),
+            self.frame_event_map.values().map(Vec::len).sum::<usize>()
+        )
+    }
+}
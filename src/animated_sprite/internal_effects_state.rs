@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::EffectDuration;
 
 /// A struct that holds the internal state related to processing AnimationEffects (for an AnimatedSprite )
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct InternalEffectsState {
     pub effect_time: EffectDuration,
     pub current_effect_duration: EffectDuration,
@@ -24,6 +24,16 @@ impl InternalEffectsState {
         }
     }
 
+    /// Creates a new InternalEffectsState for an effect lasting 1 second, which is `progress` (0.0 to 1.0) of the way through.
+    /// Useful for injecting a known effect state in tests without running a full update loop.
+    pub fn with_progress(progress: f32) -> Self {
+        InternalEffectsState {
+            effect_time: progress * 1.0,
+            current_effect_duration: 1.0,
+            ..InternalEffectsState::new()
+        }
+    }
+
     /// Resets the state of the internal effects state
     pub fn reset(&mut self) {
         self.effect_time = 0.0;
@@ -42,6 +52,21 @@ impl InternalEffectsState {
         }
     }
 
+    /// Returns the seconds left until the current effect finishes
+    pub fn remaining_time(&self) -> f32 {
+        (self.current_effect_duration - self.effect_time).max(0.0)
+    }
+
+    /// Checks if the current effect has finished playing
+    pub fn is_complete(&self) -> bool {
+        !self.is_active && self.has_played
+    }
+
+    /// Sets the progress of the current effect, clamped between 0.0 and 1.0
+    pub fn set_progress(&mut self, progress: f32) {
+        self.effect_time = progress.clamp(0.0, 1.0) * self.current_effect_duration;
+    }
+
     // fn update(&mut self, dt: f32) {
     //     if self.is_active {
     //         self.effect_time += dt;
@@ -52,3 +77,9 @@ impl InternalEffectsState {
     //     }
     // }
 }
+
+impl Default for InternalEffectsState {
+    fn default() -> Self {
+        InternalEffectsState::new()
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Controls the direction an AnimatedSprite advances its frames in, and what happens once the last frame is reached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoopMode {
+    /// Plays the frames in order, looping back to the first one
+    #[default]
+    Forward,
+    /// Plays the frames in reverse order, looping back to the last one
+    Reverse,
+    /// Plays the frames in order and then in reverse, bouncing between the first and last frames
+    PingPong,
+    /// Plays the frames in order once, then holds the last frame
+    Once,
+}
+
+impl LoopMode {
+    /// Returns the frame an animation with `total_frames` frames starts on.
+    pub fn first_frame(self, total_frames: u32) -> u32 {
+        match self {
+            LoopMode::Reverse => total_frames.saturating_sub(1),
+            _ => 0,
+        }
+    }
+
+    /// Returns the frame which follows `frame`, or None if playback stops on `frame` (the end of a `Once` loop).
+    /// `reversing` tracks which way a `PingPong` loop is currently heading.
+    pub fn next_frame(self, frame: u32, total_frames: u32, reversing: &mut bool) -> Option<u32> {
+        let last_frame = total_frames.saturating_sub(1);
+        match self {
+            LoopMode::Forward => Some(if frame >= last_frame { 0 } else { frame + 1 }),
+            LoopMode::Reverse => Some(if frame == 0 { last_frame } else { frame - 1 }),
+            LoopMode::PingPong => {
+                if last_frame == 0 {
+                    return Some(0);
+                }
+                if *reversing && frame == 0 {
+                    *reversing = false;
+                } else if !*reversing && frame >= last_frame {
+                    *reversing = true;
+                }
+                Some(if *reversing { frame - 1 } else { frame + 1 })
+            }
+            LoopMode::Once => (frame < last_frame).then_some(frame + 1),
+        }
+    }
+}
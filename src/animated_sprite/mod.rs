@@ -1,7 +1,16 @@
+#[allow(clippy::module_inception)]
 pub mod animated_sprite;
 pub mod animation;
+pub mod builder;
+#[cfg(feature = "callbacks")]
+pub mod callbacks;
 pub mod internal_effects_state;
+pub mod loop_mode;
 
 pub use animated_sprite::*;
 pub use animation::*;
+pub use builder::*;
+#[cfg(feature = "callbacks")]
+pub use callbacks::*;
 pub use internal_effects_state::*;
+pub use loop_mode::*;
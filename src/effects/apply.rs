@@ -0,0 +1,50 @@
+use crate::{X, Y};
+use macroquad::color::Color;
+use macroquad::texture::DrawTextureParams;
+
+/// Applies the FadeIn effect
+pub fn apply_fade_in(progress: f32, color: &mut Color) {
+    color.a = progress;
+}
+
+/// Applies the FadeOut effect
+pub fn apply_fade_out(progress: f32, color: &mut Color) {
+    color.a = 1.0 - progress;
+}
+
+/// Applies the Pulse effect
+pub fn apply_pulse(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    max_scale: f32,
+) {
+    let scale = 1.0 + (max_scale - 1.0) * (2.0 * std::f32::consts::PI * progress).sin().abs();
+
+    if let Some(mut size) = params.dest_size {
+        let delta_width = size.x * (scale - 1.0);
+        let delta_height = size.y * (scale - 1.0);
+
+        *x_pos -= delta_width / 2.0;
+        *y_pos -= delta_height / 2.0;
+
+        size.x *= scale;
+        size.y *= scale;
+        params.dest_size = Some(size);
+    }
+}
+
+/// Applies the Shake effect
+pub fn apply_shake(progress: f32, x_pos: &mut X, y_pos: &mut Y, intensity: f32) {
+    let shake_amount = intensity * (1.0 - progress); // Decrease shake over time
+    let angle = progress * std::f32::consts::PI * 10.0; // Arbitrary multiplier for quicker shaking
+    *x_pos += shake_amount * angle.sin();
+    *y_pos += shake_amount * angle.cos();
+}
+
+/// Applies the Bounce effect
+pub fn apply_bounce(progress: f32, y_pos: &mut Y, height: f32, bounces: u32) {
+    let bounce_progress = (progress * std::f32::consts::PI * bounces as f32).sin();
+    *y_pos -= height * bounce_progress.abs() * (1.0 - progress.powf(0.5)); // Adjust bounce decay
+}
@@ -1,15 +1,36 @@
-use crate::{AnimationEffect, AnimationEffectTrait, X, Y};
+use crate::{Animation, AnimationEffect, AnimationEffectTrait, Seconds, X, Y};
 use macroquad::color::Color;
-use macroquad::texture::{draw_texture_ex, DrawTextureParams, Image, Texture2D};
-use serde::{Deserialize, Serialize};
+use macroquad::texture::DrawTextureParams;
 use std::fmt::Debug;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+
+/// The function signature shared by all custom effects
+type CustomEffectFn =
+    dyn Fn(f32, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32) + Send + Sync;
+
+/// The function signature shared by all stateful custom effects, with mutable access to the effect's state
+type StatefulCustomEffectFn<S> =
+    dyn Fn(f32, &mut S, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32) + Send + Sync;
 
 #[cfg(feature = "custom_effects")]
 pub struct CustomEffect {
-    effect: Arc<
-        dyn Fn(f32, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32) + Send + Sync,
-    >,
+    effect: Arc<CustomEffectFn>,
+}
+
+#[cfg(feature = "custom_effects")]
+impl CustomEffect {
+    /// Creates a new CustomEffect from the given effect function
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(f32, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32)
+            + Send
+            + Sync
+            + 'static,
+    {
+        CustomEffect {
+            effect: Arc::new(f),
+        }
+    }
 }
 
 #[cfg(feature = "custom_effects")]
@@ -56,6 +77,69 @@ impl AnimationEffectTrait for CustomEffect {
     }
 }
 
+/// A custom effect which carries its own user-defined state, that the effect function can mutate every time it is applied.
+/// Each clone of the effect (ex. the same Animation registered on multiple sprites) gets its own copy of the state.
+#[cfg(feature = "custom_effects")]
+pub struct StatefulCustomEffect<S: Clone + Send + Sync + 'static> {
+    state: Mutex<S>,
+    effect: Arc<StatefulCustomEffectFn<S>>,
+}
+
+#[cfg(feature = "custom_effects")]
+impl<S: Clone + Send + Sync + 'static> StatefulCustomEffect<S> {
+    /// Returns a copy of the current state of the effect
+    pub fn state(&self) -> S {
+        self.state.lock().unwrap().clone()
+    }
+}
+
+#[cfg(feature = "custom_effects")]
+impl<S: Clone + Send + Sync + 'static> Clone for StatefulCustomEffect<S> {
+    fn clone(&self) -> Self {
+        StatefulCustomEffect {
+            state: Mutex::new(self.state()),
+            effect: Arc::clone(&self.effect),
+        }
+    }
+}
+
+#[cfg(feature = "custom_effects")]
+impl<S: Clone + Send + Sync + 'static> Debug for StatefulCustomEffect<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StatefulCustomEffect")
+    }
+}
+
+#[cfg(feature = "custom_effects")]
+impl<S: Clone + Send + Sync + 'static> AnimationEffectTrait for StatefulCustomEffect<S> {
+    fn apply(
+        &self,
+        progress: f32,
+        color: &mut Color,
+        params: &mut DrawTextureParams,
+        x_pos: &mut X,
+        y_pos: &mut Y,
+        tile_width: f32,
+        tile_height: f32,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        (self.effect)(
+            progress,
+            &mut state,
+            color,
+            params,
+            x_pos,
+            y_pos,
+            tile_width,
+            tile_height,
+        );
+    }
+
+    fn clone_box(&self) -> Box<dyn AnimationEffectTrait> {
+        Box::new(self.clone())
+    }
+}
+
 #[cfg(feature = "custom_effects")]
 impl AnimationEffect {
     /// Creates a new custom animation efefct
@@ -67,8 +151,35 @@ impl AnimationEffect {
             + Sync
             + 'static,
     {
-        AnimationEffect::Custom(Box::new(CustomEffect {
+        AnimationEffect::Custom(Box::new(CustomEffect::new(f)))
+    }
+
+    /// Creates a new custom animation effect which holds its own mutable state, starting from `initial_state`
+    pub fn new_stateful_custom<S, F>(initial_state: S, f: F) -> AnimationEffect
+    where
+        S: Clone + Send + Sync + 'static,
+        F: Fn(f32, &mut S, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32)
+            + Send
+            + Sync
+            + 'static,
+    {
+        AnimationEffect::Custom(Box::new(StatefulCustomEffect {
+            state: Mutex::new(initial_state),
             effect: Arc::new(f),
         }))
     }
 }
+
+#[cfg(feature = "custom_effects")]
+impl Animation {
+    /// Add a custom start animation effect directly from a function, in one step. Duration is represented in seconds from the start.
+    pub fn with_custom_start_effect<F>(self, f: F, duration: Seconds) -> Self
+    where
+        F: Fn(f32, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32)
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.with_start_effect(AnimationEffect::new_custom(f), duration)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{AnimationEffect, AnimationEffectTrait, CustomEffect, X, Y};
+use macroquad::color::Color;
+use macroquad::texture::DrawTextureParams;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// A factory which creates a fresh instance of a registered custom effect
+pub type CustomEffectFactory = Arc<dyn Fn() -> Box<dyn AnimationEffectTrait> + Send + Sync>;
+
+/// The global registry of named custom effects, used to look effects back up by name when deserializing
+fn registry() -> &'static RwLock<HashMap<&'static str, CustomEffectFactory>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<&'static str, CustomEffectFactory>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a custom effect factory under the given name. Registering under an existing name replaces the old factory.
+/// All named custom effects must be registered at startup before any sprites using them are deserialized.
+pub fn register_custom_effect(name: &'static str, factory: CustomEffectFactory) {
+    registry().write().unwrap().insert(name, factory);
+}
+
+/// Creates a new instance of the custom effect registered under the given name, if any.
+pub fn create_registered_custom_effect(name: &str) -> Option<Box<dyn AnimationEffectTrait>> {
+    registry()
+        .read()
+        .unwrap()
+        .get(name)
+        .map(|factory| factory())
+}
+
+/// Returns the names of all registered custom effects in alphabetical order (ex. for an editor's effect dropdown).
+pub fn list_registered_custom_effects() -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = registry().read().unwrap().keys().copied().collect();
+    names.sort_unstable();
+    names
+}
+
+/// A custom effect paired with the name it is registered under, which allows it to be serialized as just its name.
+pub struct NamedCustomEffect {
+    name: &'static str,
+    effect: Box<dyn AnimationEffectTrait>,
+}
+
+impl NamedCustomEffect {
+    /// Returns the name the effect is registered under
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Named custom effects are equal if they are registered under the same name
+impl PartialEq for NamedCustomEffect {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Clone for NamedCustomEffect {
+    fn clone(&self) -> Self {
+        NamedCustomEffect {
+            name: self.name,
+            effect: self.effect.clone_box(),
+        }
+    }
+}
+
+impl Debug for NamedCustomEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NamedCustomEffect({})", self.name)
+    }
+}
+
+impl AnimationEffectTrait for NamedCustomEffect {
+    fn apply(
+        &self,
+        progress: f32,
+        color: &mut Color,
+        params: &mut DrawTextureParams,
+        x_pos: &mut X,
+        y_pos: &mut Y,
+        tile_width: f32,
+        tile_height: f32,
+    ) {
+        self.effect.apply(
+            progress,
+            color,
+            params,
+            x_pos,
+            y_pos,
+            tile_width,
+            tile_height,
+        );
+    }
+
+    fn clone_box(&self) -> Box<dyn AnimationEffectTrait> {
+        Box::new(self.clone())
+    }
+}
+
+impl Serialize for NamedCustomEffect {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name)
+    }
+}
+
+impl<'de> Deserialize<'de> for NamedCustomEffect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        let registry = registry().read().unwrap();
+        let (name, factory) = registry.get_key_value(name.as_str()).ok_or_else(|| {
+            de::Error::custom(format!("no custom effect registered under `{}`", name))
+        })?;
+
+        Ok(NamedCustomEffect {
+            name,
+            effect: factory(),
+        })
+    }
+}
+
+impl AnimationEffect {
+    /// Creates a new custom animation effect which is registered under `name`, making it serializable.
+    /// Of note, this must be called at startup for every named effect before deserializing any sprites which use it.
+    pub fn new_custom_named<F>(name: &'static str, f: F) -> AnimationEffect
+    where
+        F: Fn(f32, &mut Color, &mut DrawTextureParams, &mut X, &mut Y, f32, f32)
+            + Send
+            + Sync
+            + 'static,
+    {
+        let custom_effect = CustomEffect::new(f);
+        register_custom_effect(name, Arc::new(move || custom_effect.clone_box()));
+
+        AnimationEffect::CustomNamed(NamedCustomEffect {
+            name,
+            effect: create_registered_custom_effect(name).unwrap(),
+        })
+    }
+}
@@ -1,12 +1,19 @@
-use crate::{EffectColor, FlipDirection, SlideDirection, X, Y};
+#[cfg(feature = "custom_effects")]
+use crate::NamedCustomEffect;
+use crate::{
+    apply_bounce, apply_fade_in, apply_fade_out, apply_pulse, apply_shake, EffectColor,
+    FlipDirection, Seconds, SlideDirection, X, Y,
+};
+use macroquad::color::Color;
 use macroquad::prelude::*;
 use macroquad::texture::DrawTextureParams;
-use macroquad::{color::Color, rand::rand};
 use serde::{Deserialize, Serialize};
+use std::cell::OnceCell;
 use std::fmt::Debug;
 
 /// An internal trait used for allowing custom animation effects to be possible
 pub trait AnimationEffectTrait: Debug + Send + Sync {
+    #[allow(clippy::too_many_arguments)]
     fn apply(
         &self,
         progress: f32,
@@ -20,8 +27,15 @@ pub trait AnimationEffectTrait: Debug + Send + Sync {
     fn clone_box(&self) -> Box<dyn AnimationEffectTrait>;
 }
 
+/// Custom effects are closures which can't be compared, so they are never equal (not even to themselves)
+impl PartialEq for dyn AnimationEffectTrait {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
 /// AnimationEffects provide a variety of baked-in options for enhancing how your AnimatedSprite is drawn.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum AnimationEffect {
     FadeIn,
     FadeOut,
@@ -51,9 +65,276 @@ pub enum AnimationEffect {
     SquashFlipHorizontal(f32),
     /// New effect colors
     ColorCycle(Vec<EffectColor>),
+    /// Side to draw the mirrored copy of the sprite on
+    Mirror(SlideDirection),
+    /// Color to draw the sprite's shape in
+    Silhouette(EffectColor),
+    /// Number of discrete opacity steps
+    StepFadeIn(u32),
+    /// Number of discrete opacity steps
+    StepFadeOut(u32),
+    /// EffectColor to glow towards, maximum glow intensity (0.0 to 1.0)
+    GlowPulse(EffectColor, f32),
+    /// Intensity of the shimmer
+    HeatDistortion(f32),
+    /// EffectColor to fill the unrevealed area with, number of columns to reveal the sprite in
+    Typewriter(EffectColor, u32),
+    /// Horizontal amplitude, vertical amplitude of the jitter
+    Jitter(f32, f32),
+    /// Speed multiplier of the spin while shrinking away
+    Swirl(f32),
+    /// Speed multiplier of the spin while growing in
+    SwirlIn(f32),
+    /// Horizontal scale, vertical scale to stretch towards, 1.0 = 100%
+    Stretch(f32, f32),
+    /// Horizontal scale, vertical scale to stretch towards and back, 1.0 = 100%
+    StretchPulse(f32, f32),
+    /// Probability (0.0 to 1.0) of each frame being dropped
+    DropFrame(f32),
+    /// Seconds to hold the current frame for, from when the effect starts
+    Freeze(Seconds),
+    /// EffectColor to start from, EffectColor to end at
+    LerpToColor(EffectColor, EffectColor),
+    /// Minimum alpha, maximum alpha (both 0.0 to 1.0) to randomly flicker between
+    FlickerAlpha(f32, f32),
+    /// Intensity of the noise (0.0 to 1.0)
+    StaticNoise(f32),
+    /// Maximum outward displacement of the shockwave in pixels
+    ShockWave(f32),
+    /// Maximum fraction of the height to compress by
+    Squint(f32),
+    /// Fraction to overshoot the full size by before settling, 0.2 = 20%
+    Inflate(f32),
+    /// Fraction past nothing to shrink towards, making the sprite vanish before the effect ends, 0.2 = 20%
+    Deflate(f32),
+    /// Maximum radius the rings spread to, number of rings
+    Ripple(f32, u32),
+    /// Horizontal intensity, vertical intensity of the tremble
+    Tremble(f32, f32),
+    /// EffectColor to flash, number of flashes
+    LightningBurst(EffectColor, u32),
+    /// Effects to cycle through, one per frame of the animation
+    CycleFrameEffect(Vec<AnimationEffect>),
+    /// Progress threshold (0.0 to 1.0), effect which only applies once the progress reaches the threshold
+    After(f32, Box<AnimationEffect>),
+    /// Progress threshold (0.0 to 1.0), effect which only applies until the progress reaches the threshold
+    Before(f32, Box<AnimationEffect>),
     #[cfg(feature = "custom_effects")]
     #[serde(skip)]
     Custom(Box<dyn AnimationEffectTrait>),
+    /// A custom effect registered under a name, which serializes as that name
+    #[cfg(feature = "custom_effects")]
+    CustomNamed(NamedCustomEffect),
+}
+
+impl AnimationEffect {
+    /// Returns the effect which applies on the given frame of the animation. This is the inner effect picked by
+    /// `CycleFrameEffect` (frame modulo the number of effects), or the effect itself for every other effect.
+    pub fn effect_for_frame(&self, frame: u32) -> &AnimationEffect {
+        match self {
+            AnimationEffect::CycleFrameEffect(effects) if !effects.is_empty() => {
+                effects[frame as usize % effects.len()].effect_for_frame(frame)
+            }
+            effect => effect,
+        }
+    }
+
+    /// Returns if the effect holds the current frame `elapsed` seconds into an effect lasting `duration` seconds.
+    /// This is true for `Freeze` until its seconds pass, including when picked by `CycleFrameEffect` for the given frame or
+    /// nested in `After`/`Before`, where it starts (or is cut off) once the progress reaches the threshold.
+    pub fn freezes_frame(&self, frame: u32, elapsed: Seconds, duration: Seconds) -> bool {
+        match self.effect_for_frame(frame) {
+            AnimationEffect::Freeze(freeze_duration) => elapsed < *freeze_duration,
+            AnimationEffect::After(threshold, effect) => {
+                let start = threshold * duration;
+                elapsed >= start && effect.freezes_frame(frame, elapsed - start, duration - start)
+            }
+            AnimationEffect::Before(threshold, effect) => {
+                let end = threshold * duration;
+                elapsed < end && effect.freezes_frame(frame, elapsed, end)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the effect drawn on the given frame at `progress` along with its own progress, looking through
+    /// `CycleFrameEffect`, `After` and `Before`, or None while an `After` hasn't reached its threshold yet (or a `Before` has).
+    /// Used for the effects which are drawn rather than applied (ex. Mirror, Ripple, Typewriter), so they keep the same timing when nested.
+    pub fn resolve_at_progress(
+        &self,
+        frame: u32,
+        progress: f32,
+    ) -> Option<(&AnimationEffect, f32)> {
+        match self.effect_for_frame(frame) {
+            AnimationEffect::After(threshold, effect) => {
+                effect.resolve_at_progress(frame, after_progress(progress, *threshold)?)
+            }
+            AnimationEffect::Before(threshold, effect) => {
+                effect.resolve_at_progress(frame, before_progress(progress, *threshold)?)
+            }
+            effect => Some((effect, progress)),
+        }
+    }
+
+    /// Returns the name of the effect's variant (ex. `"Pulse"`), for dev consoles, editors and logging.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnimationEffect::FadeIn => "FadeIn",
+            AnimationEffect::FadeOut => "FadeOut",
+            AnimationEffect::SlideIn(_) => "SlideIn",
+            AnimationEffect::SlideOut(_) => "SlideOut",
+            AnimationEffect::Spin => "Spin",
+            AnimationEffect::Pulse(_) => "Pulse",
+            AnimationEffect::Blinking(_, _) => "Blinking",
+            AnimationEffect::Shake(_) => "Shake",
+            AnimationEffect::Wobble(_) => "Wobble",
+            AnimationEffect::Bounce(_, _) => "Bounce",
+            AnimationEffect::BasicFlip(_) => "BasicFlip",
+            AnimationEffect::Glitch(_) => "Glitch",
+            AnimationEffect::ShearLeft(_) => "ShearLeft",
+            AnimationEffect::ShearRight(_) => "ShearRight",
+            AnimationEffect::SquashFlipVertical(_) => "SquashFlipVertical",
+            AnimationEffect::SquashFlipHorizontal(_) => "SquashFlipHorizontal",
+            AnimationEffect::ColorCycle(_) => "ColorCycle",
+            AnimationEffect::Mirror(_) => "Mirror",
+            AnimationEffect::Silhouette(_) => "Silhouette",
+            AnimationEffect::StepFadeIn(_) => "StepFadeIn",
+            AnimationEffect::StepFadeOut(_) => "StepFadeOut",
+            AnimationEffect::GlowPulse(_, _) => "GlowPulse",
+            AnimationEffect::HeatDistortion(_) => "HeatDistortion",
+            AnimationEffect::Typewriter(_, _) => "Typewriter",
+            AnimationEffect::Jitter(_, _) => "Jitter",
+            AnimationEffect::Swirl(_) => "Swirl",
+            AnimationEffect::SwirlIn(_) => "SwirlIn",
+            AnimationEffect::Stretch(_, _) => "Stretch",
+            AnimationEffect::StretchPulse(_, _) => "StretchPulse",
+            AnimationEffect::DropFrame(_) => "DropFrame",
+            AnimationEffect::Freeze(_) => "Freeze",
+            AnimationEffect::LerpToColor(_, _) => "LerpToColor",
+            AnimationEffect::FlickerAlpha(_, _) => "FlickerAlpha",
+            AnimationEffect::StaticNoise(_) => "StaticNoise",
+            AnimationEffect::ShockWave(_) => "ShockWave",
+            AnimationEffect::Squint(_) => "Squint",
+            AnimationEffect::Inflate(_) => "Inflate",
+            AnimationEffect::Deflate(_) => "Deflate",
+            AnimationEffect::Ripple(_, _) => "Ripple",
+            AnimationEffect::Tremble(_, _) => "Tremble",
+            AnimationEffect::LightningBurst(_, _) => "LightningBurst",
+            AnimationEffect::CycleFrameEffect(_) => "CycleFrameEffect",
+            AnimationEffect::After(_, _) => "After",
+            AnimationEffect::Before(_, _) => "Before",
+            #[cfg(feature = "custom_effects")]
+            AnimationEffect::Custom(_) => "Custom",
+            #[cfg(feature = "custom_effects")]
+            AnimationEffect::CustomNamed(_) => "CustomNamed",
+        }
+    }
+
+    /// Returns the name of the effect alongside its parameters (ex. `"Bounce(height=20.0, bounces=3)"`).
+    pub fn describe(&self) -> String {
+        match self {
+            AnimationEffect::SlideIn(direction) => format!("SlideIn(direction={:?})", direction),
+            AnimationEffect::SlideOut(direction) => format!("SlideOut(direction={:?})", direction),
+            AnimationEffect::Pulse(max_scale) => format!("Pulse(max_scale={:?})", max_scale),
+            AnimationEffect::Blinking(color, blinks) => {
+                format!("Blinking(color={:?}, blinks={})", color, blinks)
+            }
+            AnimationEffect::Shake(intensity) => format!("Shake(intensity={:?})", intensity),
+            AnimationEffect::Wobble(intensity) => format!("Wobble(intensity={:?})", intensity),
+            AnimationEffect::Bounce(height, bounces) => {
+                format!("Bounce(height={:?}, bounces={})", height, bounces)
+            }
+            AnimationEffect::BasicFlip(direction) => {
+                format!("BasicFlip(direction={:?})", direction)
+            }
+            AnimationEffect::Glitch(intensity) => format!("Glitch(intensity={:?})", intensity),
+            AnimationEffect::ShearLeft(intensity) => {
+                format!("ShearLeft(intensity={:?})", intensity)
+            }
+            AnimationEffect::ShearRight(intensity) => {
+                format!("ShearRight(intensity={:?})", intensity)
+            }
+            AnimationEffect::SquashFlipVertical(intensity) => {
+                format!("SquashFlipVertical(intensity={:?})", intensity)
+            }
+            AnimationEffect::SquashFlipHorizontal(intensity) => {
+                format!("SquashFlipHorizontal(intensity={:?})", intensity)
+            }
+            AnimationEffect::ColorCycle(colors) => format!("ColorCycle(colors={:?})", colors),
+            AnimationEffect::Mirror(direction) => format!("Mirror(direction={:?})", direction),
+            AnimationEffect::Silhouette(color) => format!("Silhouette(color={:?})", color),
+            AnimationEffect::StepFadeIn(steps) => format!("StepFadeIn(steps={})", steps),
+            AnimationEffect::StepFadeOut(steps) => format!("StepFadeOut(steps={})", steps),
+            AnimationEffect::GlowPulse(color, intensity) => {
+                format!("GlowPulse(color={:?}, intensity={:?})", color, intensity)
+            }
+            AnimationEffect::HeatDistortion(intensity) => {
+                format!("HeatDistortion(intensity={:?})", intensity)
+            }
+            AnimationEffect::Typewriter(fill_color, columns) => format!(
+                "Typewriter(fill_color={:?}, columns={})",
+                fill_color, columns
+            ),
+            AnimationEffect::Jitter(amplitude_x, amplitude_y) => format!(
+                "Jitter(amplitude_x={:?}, amplitude_y={:?})",
+                amplitude_x, amplitude_y
+            ),
+            AnimationEffect::Swirl(speed) => format!("Swirl(speed={:?})", speed),
+            AnimationEffect::SwirlIn(speed) => format!("SwirlIn(speed={:?})", speed),
+            AnimationEffect::Stretch(scale_x, scale_y) => {
+                format!("Stretch(scale_x={:?}, scale_y={:?})", scale_x, scale_y)
+            }
+            AnimationEffect::StretchPulse(scale_x, scale_y) => {
+                format!("StretchPulse(scale_x={:?}, scale_y={:?})", scale_x, scale_y)
+            }
+            AnimationEffect::DropFrame(probability) => {
+                format!("DropFrame(probability={:?})", probability)
+            }
+            AnimationEffect::Freeze(duration) => format!("Freeze(duration={:?})", duration),
+            AnimationEffect::LerpToColor(start_color, end_color) => format!(
+                "LerpToColor(start_color={:?}, end_color={:?})",
+                start_color, end_color
+            ),
+            AnimationEffect::FlickerAlpha(min_alpha, max_alpha) => format!(
+                "FlickerAlpha(min_alpha={:?}, max_alpha={:?})",
+                min_alpha, max_alpha
+            ),
+            AnimationEffect::StaticNoise(intensity) => {
+                format!("StaticNoise(intensity={:?})", intensity)
+            }
+            AnimationEffect::ShockWave(radius) => format!("ShockWave(radius={:?})", radius),
+            AnimationEffect::Squint(intensity) => format!("Squint(intensity={:?})", intensity),
+            AnimationEffect::Inflate(overshoot) => format!("Inflate(overshoot={:?})", overshoot),
+            AnimationEffect::Deflate(undershoot) => format!("Deflate(undershoot={:?})", undershoot),
+            AnimationEffect::Ripple(radius, rings) => {
+                format!("Ripple(radius={:?}, rings={})", radius, rings)
+            }
+            AnimationEffect::Tremble(intensity_x, intensity_y) => format!(
+                "Tremble(intensity_x={:?}, intensity_y={:?})",
+                intensity_x, intensity_y
+            ),
+            AnimationEffect::LightningBurst(color, flashes) => {
+                format!("LightningBurst(color={:?}, flashes={})", color, flashes)
+            }
+            AnimationEffect::CycleFrameEffect(effects) => format!(
+                "CycleFrameEffect(effects=[{}])",
+                effects
+                    .iter()
+                    .map(AnimationEffect::describe)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            AnimationEffect::After(threshold, effect) => {
+                format!("After(threshold={:?}, effect={})", threshold, effect)
+            }
+            AnimationEffect::Before(threshold, effect) => {
+                format!("Before(threshold={:?}, effect={})", threshold, effect)
+            }
+            #[cfg(feature = "custom_effects")]
+            AnimationEffect::CustomNamed(effect) => format!("CustomNamed(name={})", effect.name()),
+            effect => effect.name().to_string(),
+        }
+    }
 }
 
 impl Clone for AnimationEffect {
@@ -82,12 +363,75 @@ impl Clone for AnimationEffect {
                 AnimationEffect::SquashFlipHorizontal(*scale)
             }
             AnimationEffect::ColorCycle(colors) => AnimationEffect::ColorCycle(colors.clone()),
+            AnimationEffect::Mirror(direction) => AnimationEffect::Mirror(direction.clone()),
+            AnimationEffect::Silhouette(color) => AnimationEffect::Silhouette(color.clone()),
+            AnimationEffect::StepFadeIn(steps) => AnimationEffect::StepFadeIn(*steps),
+            AnimationEffect::StepFadeOut(steps) => AnimationEffect::StepFadeOut(*steps),
+            AnimationEffect::GlowPulse(color, intensity) => {
+                AnimationEffect::GlowPulse(color.clone(), *intensity)
+            }
+            AnimationEffect::HeatDistortion(intensity) => {
+                AnimationEffect::HeatDistortion(*intensity)
+            }
+            AnimationEffect::Typewriter(color, columns) => {
+                AnimationEffect::Typewriter(color.clone(), *columns)
+            }
+            AnimationEffect::Jitter(amplitude_x, amplitude_y) => {
+                AnimationEffect::Jitter(*amplitude_x, *amplitude_y)
+            }
+            AnimationEffect::Swirl(speed) => AnimationEffect::Swirl(*speed),
+            AnimationEffect::SwirlIn(speed) => AnimationEffect::SwirlIn(*speed),
+            AnimationEffect::Stretch(scale_x, scale_y) => {
+                AnimationEffect::Stretch(*scale_x, *scale_y)
+            }
+            AnimationEffect::StretchPulse(scale_x, scale_y) => {
+                AnimationEffect::StretchPulse(*scale_x, *scale_y)
+            }
+            AnimationEffect::DropFrame(probability) => AnimationEffect::DropFrame(*probability),
+            AnimationEffect::Freeze(duration) => AnimationEffect::Freeze(*duration),
+            AnimationEffect::LerpToColor(start_color, end_color) => {
+                AnimationEffect::LerpToColor(start_color.clone(), end_color.clone())
+            }
+            AnimationEffect::FlickerAlpha(min_alpha, max_alpha) => {
+                AnimationEffect::FlickerAlpha(*min_alpha, *max_alpha)
+            }
+            AnimationEffect::StaticNoise(intensity) => AnimationEffect::StaticNoise(*intensity),
+            AnimationEffect::ShockWave(radius) => AnimationEffect::ShockWave(*radius),
+            AnimationEffect::Squint(intensity) => AnimationEffect::Squint(*intensity),
+            AnimationEffect::Inflate(overshoot) => AnimationEffect::Inflate(*overshoot),
+            AnimationEffect::Deflate(undershoot) => AnimationEffect::Deflate(*undershoot),
+            AnimationEffect::Ripple(intensity, rings) => {
+                AnimationEffect::Ripple(*intensity, *rings)
+            }
+            AnimationEffect::Tremble(horizontal_intensity, vertical_intensity) => {
+                AnimationEffect::Tremble(*horizontal_intensity, *vertical_intensity)
+            }
+            AnimationEffect::LightningBurst(flash_color, flashes) => {
+                AnimationEffect::LightningBurst(flash_color.clone(), *flashes)
+            }
+            AnimationEffect::CycleFrameEffect(effects) => {
+                AnimationEffect::CycleFrameEffect(effects.clone())
+            }
+            AnimationEffect::After(threshold, effect) => {
+                AnimationEffect::After(*threshold, effect.clone())
+            }
+            AnimationEffect::Before(threshold, effect) => {
+                AnimationEffect::Before(*threshold, effect.clone())
+            }
             #[cfg(feature = "custom_effects")]
             AnimationEffect::Custom(effect) => AnimationEffect::Custom(effect.clone_box()),
+            #[cfg(feature = "custom_effects")]
+            AnimationEffect::CustomNamed(effect) => AnimationEffect::CustomNamed(effect.clone()),
         }
     }
 }
 
+impl std::fmt::Display for AnimationEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.describe())
+    }
+}
+
 impl AnimationEffectTrait for AnimationEffect {
     /// Clones the current AnimationEffect as a Box<dyn AnimationEffectTrait>
     fn clone_box(&self) -> Box<dyn AnimationEffectTrait> {
@@ -137,12 +481,149 @@ impl AnimationEffectTrait for AnimationEffect {
                 apply_shear_right(progress, params, x_pos, *intensity);
             }
             AnimationEffect::SquashFlipVertical(intensity) => {
-                apply_squash_vertical(progress, params, y_pos, *intensity, tile_height)
+                // Decrease squash over time
+                let squash_amount = intensity * (1.0 - progress.powf(2.0));
+                apply_squash_vertical_centered(params, y_pos, squash_amount)
             }
             AnimationEffect::SquashFlipHorizontal(intensity) => {
                 apply_squash_horizontal(progress, params, x_pos, *intensity, tile_width)
             }
             AnimationEffect::ColorCycle(palette) => apply_color_cycle(progress, color, palette),
+            // The mirrored copy is drawn separately by the AnimatedSprite, the sprite itself is untouched
+            AnimationEffect::Mirror(_) => {}
+            AnimationEffect::Silhouette(silhouette_color) => {
+                apply_silhouette(color, silhouette_color)
+            }
+            AnimationEffect::StepFadeIn(steps) => apply_step_fade_in(progress, color, *steps),
+            AnimationEffect::StepFadeOut(steps) => apply_step_fade_out(progress, color, *steps),
+            AnimationEffect::GlowPulse(glow_color, max_intensity) => {
+                apply_glow_pulse(progress, color, glow_color, *max_intensity)
+            }
+            AnimationEffect::HeatDistortion(intensity) => {
+                apply_heat_distortion(progress, y_pos, *intensity)
+            }
+            // The unrevealed area is filled in separately by the AnimatedSprite
+            AnimationEffect::Typewriter(_, columns) => apply_typewriter(progress, params, *columns),
+            AnimationEffect::Jitter(amplitude_x, amplitude_y) => {
+                apply_jitter(x_pos, y_pos, *amplitude_x, *amplitude_y)
+            }
+            AnimationEffect::Swirl(speed) => apply_swirl(
+                progress,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                *speed,
+            ),
+            AnimationEffect::SwirlIn(speed) => apply_swirl_in(
+                progress,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                *speed,
+            ),
+            AnimationEffect::Stretch(scale_x, scale_y) => apply_stretch(
+                progress,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                (*scale_x, *scale_y),
+            ),
+            AnimationEffect::StretchPulse(scale_x, scale_y) => apply_stretch(
+                (2.0 * std::f32::consts::PI * progress).sin().abs(),
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                (*scale_x, *scale_y),
+            ),
+            // Dropped frames are skipped entirely by the AnimatedSprite draw methods
+            AnimationEffect::DropFrame(_) => {}
+            // Freezing frames is handled by the AnimatedSprite when updating, nothing changes visually
+            AnimationEffect::Freeze(_) => {}
+            AnimationEffect::LerpToColor(start_color, end_color) => {
+                apply_lerp_to_color(progress, color, start_color, end_color)
+            }
+            AnimationEffect::FlickerAlpha(min_alpha, max_alpha) => {
+                apply_flicker_alpha(color, *min_alpha, *max_alpha)
+            }
+            AnimationEffect::StaticNoise(intensity) => apply_static_noise(color, *intensity),
+            AnimationEffect::ShockWave(radius) => apply_shock_wave(
+                progress,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                *radius,
+            ),
+            AnimationEffect::Squint(intensity) => apply_squint(progress, params, y_pos, *intensity),
+            AnimationEffect::Inflate(overshoot) => apply_inflate(
+                progress,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                *overshoot,
+            ),
+            AnimationEffect::Deflate(undershoot) => apply_deflate(
+                progress,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                *undershoot,
+            ),
+            // The rings are drawn separately by the AnimatedSprite, the sprite itself is untouched
+            AnimationEffect::Ripple(_, _) => {}
+            AnimationEffect::Tremble(horizontal_intensity, vertical_intensity) => apply_tremble(
+                progress,
+                x_pos,
+                y_pos,
+                *horizontal_intensity,
+                *vertical_intensity,
+            ),
+            AnimationEffect::LightningBurst(flash_color, flashes) => {
+                apply_lightning_burst(progress, color, flash_color, *flashes)
+            }
+            AnimationEffect::CycleFrameEffect(effects) => apply_cycle_frame_effect(
+                progress,
+                color,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                effects,
+            ),
+            AnimationEffect::After(threshold, effect) => apply_after(
+                progress,
+                color,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                (*threshold, effect),
+            ),
+            AnimationEffect::Before(threshold, effect) => apply_before(
+                progress,
+                color,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+                (*threshold, effect),
+            ),
             #[cfg(feature = "custom_effects")]
             AnimationEffect::Custom(effect) => effect.apply(
                 progress,
@@ -153,18 +634,42 @@ impl AnimationEffectTrait for AnimationEffect {
                 tile_width,
                 tile_height,
             ),
+            #[cfg(feature = "custom_effects")]
+            AnimationEffect::CustomNamed(effect) => effect.apply(
+                progress,
+                color,
+                params,
+                x_pos,
+                y_pos,
+                tile_width,
+                tile_height,
+            ),
         }
     }
 }
 
-/// Applies the FadeIn effect
-fn apply_fade_in(progress: f32, color: &mut Color) {
-    color.a = progress;
-}
-
-/// Applies the FadeOut effect
-fn apply_fade_out(progress: f32, color: &mut Color) {
-    color.a = 1.0 - progress;
+/// Applies an AnimationEffect to the given draw state at the given progress (0.0 to 1.0), outside of an AnimatedSprite
+/// (ex. in an effect preview panel or a custom renderer). Same as calling `AnimationEffectTrait::apply` on the effect.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_animation_effect(
+    effect: &AnimationEffect,
+    progress: f32,
+    color: &mut Color,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+) {
+    effect.apply(
+        progress,
+        color,
+        params,
+        x_pos,
+        y_pos,
+        tile_width,
+        tile_height,
+    );
 }
 
 /// Applies the SlideIn effect
@@ -214,29 +719,6 @@ fn apply_spin(progress: f32, params: &mut DrawTextureParams) {
     params.rotation = rotation;
 }
 
-/// Applies the Pulse effect
-fn apply_pulse(
-    progress: f32,
-    params: &mut DrawTextureParams,
-    x_pos: &mut X,
-    y_pos: &mut Y,
-    max_scale: f32,
-) {
-    let scale = 1.0 + (max_scale - 1.0) * (2.0 * std::f32::consts::PI * progress).sin().abs();
-
-    if let Some(mut size) = params.dest_size {
-        let delta_width = size.x * (scale - 1.0);
-        let delta_height = size.y * (scale - 1.0);
-
-        *x_pos -= delta_width / 2.0;
-        *y_pos -= delta_height / 2.0;
-
-        size.x *= scale;
-        size.y *= scale;
-        params.dest_size = Some(size);
-    }
-}
-
 /// Applies the Blinking effect
 fn apply_blinking(progress: f32, color: &mut Color, blink_color: &EffectColor, blinks: u32) {
     let blink_duration = 1.0 / (blinks as f32);
@@ -267,14 +749,6 @@ fn apply_blinking(progress: f32, color: &mut Color, blink_color: &EffectColor, b
     color.b = color.b * (1.0 - blink_intensity) + target_color.b * blink_intensity;
 }
 
-/// Applies the Shake effect
-fn apply_shake(progress: f32, x_pos: &mut X, y_pos: &mut Y, intensity: f32) {
-    let shake_amount = intensity * (1.0 - progress); // Decrease shake over time
-    let angle = progress * std::f32::consts::PI * 10.0; // Arbitrary multiplier for quicker shaking
-    *x_pos += shake_amount * angle.sin();
-    *y_pos += shake_amount * angle.cos();
-}
-
 /// Applies the Wobble effect
 fn apply_wobble(progress: f32, params: &mut DrawTextureParams, intensity: f32) {
     let wobble_amount = intensity * (1.0 - progress.powf(2.0)); // Decrease wobble over time
@@ -292,12 +766,6 @@ fn apply_wobble(progress: f32, params: &mut DrawTextureParams, intensity: f32) {
     }
 }
 
-/// Applies the Bounce effect
-fn apply_bounce(progress: f32, y_pos: &mut Y, height: f32, bounces: u32) {
-    let bounce_progress = (progress * std::f32::consts::PI * bounces as f32).sin();
-    *y_pos -= height * bounce_progress.abs() * (1.0 - progress.powf(0.5)); // Adjust bounce decay
-}
-
 /// Applies the BasicFlip effect
 fn apply_basic_flip(params: &mut DrawTextureParams, direction: &FlipDirection) {
     match direction {
@@ -438,15 +906,13 @@ fn apply_shear_left(progress: f32, params: &mut DrawTextureParams, intensity: f3
     }
 }
 
-/// Applies the squash effect vertically
-fn apply_squash_vertical(
-    progress: f32,
+/// Squashes the sprite vertically by `squash_amount` (fraction of its height), anchored at its vertical center.
+/// Used by both SquashFlipVertical and Squint.
+fn apply_squash_vertical_centered(
     params: &mut DrawTextureParams,
     y_pos: &mut Y,
-    intensity: f32,
-    tile_height: f32,
+    squash_amount: f32,
 ) {
-    let squash_amount = intensity * (1.0 - progress.powf(2.0)); // Decrease squash over time
     if let Some(mut dest_size) = params.dest_size {
         let original_height = dest_size.y;
         dest_size.y *= 1.0 - squash_amount;
@@ -463,7 +929,7 @@ fn apply_squash_horizontal(
     params: &mut DrawTextureParams,
     x_pos: &mut X,
     intensity: f32,
-    tile_width: f32,
+    _tile_width: f32,
 ) {
     let squash_amount = intensity * (1.0 - progress.powf(2.0)); // Decrease squash over time
     if let Some(mut dest_size) = params.dest_size {
@@ -496,6 +962,856 @@ fn apply_color_cycle(progress: f32, color: &mut Color, palette: &[EffectColor])
     );
 }
 
+/// Applies the Silhouette effect.
+/// Of note, macroquad tints textures by multiplying their colors, so on its own this can only darken the sprite towards
+/// the silhouette color (only black is exact). The AnimatedSprite draw methods draw Silhouette with `silhouette_material`
+/// to get a true flat color shape.
+fn apply_silhouette(color: &mut Color, silhouette_color: &EffectColor) {
+    let target_color = silhouette_color.to_color();
+    color.r = target_color.r;
+    color.g = target_color.g;
+    color.b = target_color.b;
+}
+
+const SILHOUETTE_VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+varying lowp vec2 uv;
+varying lowp vec4 color;
+uniform mat4 Model;
+uniform mat4 Projection;
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    color = color0 / 255.0;
+    uv = texcoord;
+}
+"#;
+
+const SILHOUETTE_FRAGMENT_SHADER: &str = r#"#version 100
+varying lowp vec4 color;
+varying lowp vec2 uv;
+uniform sampler2D Texture;
+void main() {
+    gl_FragColor = vec4(color.rgb, texture2D(Texture, uv).a * color.a);
+}
+"#;
+
+thread_local! {
+    static SILHOUETTE_MATERIAL: OnceCell<Option<Material>> = const { OnceCell::new() };
+}
+
+/// Returns the material used to draw the Silhouette effect, which keeps only the texture's alpha and fills it with the draw color.
+/// Loaded once on first use, returns None if the shader could not be loaded (the draw then falls back to a regular tint).
+pub(crate) fn silhouette_material() -> Option<Material> {
+    SILHOUETTE_MATERIAL.with(|material| {
+        material
+            .get_or_init(|| {
+                load_material(
+                    ShaderSource::Glsl {
+                        vertex: SILHOUETTE_VERTEX_SHADER,
+                        fragment: SILHOUETTE_FRAGMENT_SHADER,
+                    },
+                    MaterialParams::default(),
+                )
+                .ok()
+            })
+            .clone()
+    })
+}
+
+/// Applies the StepFadeIn effect
+fn apply_step_fade_in(progress: f32, color: &mut Color, steps: u32) {
+    let steps = steps.max(1); // 0 steps is treated as a single instant step
+    let step = ((progress * steps as f32) as u32).min(steps);
+    color.a = step as f32 / steps as f32;
+}
+
+/// Applies the StepFadeOut effect
+fn apply_step_fade_out(progress: f32, color: &mut Color, steps: u32) {
+    apply_step_fade_in(progress, color, steps);
+    color.a = 1.0 - color.a;
+}
+
+/// Applies the GlowPulse effect
+fn apply_glow_pulse(
+    progress: f32,
+    color: &mut Color,
+    glow_color: &EffectColor,
+    max_intensity: f32,
+) {
+    let intensity = max_intensity * (progress * 2.0 * std::f32::consts::PI).sin().abs();
+
+    let target_color = glow_color.to_color();
+    color.r = lerp(color.r, target_color.r, intensity);
+    color.g = lerp(color.g, target_color.g, intensity);
+    color.b = lerp(color.b, target_color.b, intensity);
+}
+
+/// Applies the HeatDistortion effect
+fn apply_heat_distortion(progress: f32, y_pos: &mut Y, intensity: f32) {
+    // Two out of sync sine waves give a wavy shimmer that is still fully deterministic (unlike Shake/Glitch)
+    let angle = progress * std::f32::consts::PI;
+    *y_pos += intensity * (angle * 7.0).sin() * 0.3 + intensity * (angle * 13.0).sin() * 0.15;
+}
+
+/// Returns the fraction of the sprite's width which is revealed by the Typewriter effect, snapped to its columns
+pub(crate) fn typewriter_revealed_fraction(progress: f32, columns: u32) -> f32 {
+    let columns = columns.max(1);
+    ((progress * columns as f32).floor() / columns as f32).min(1.0)
+}
+
+/// Applies the Typewriter effect
+fn apply_typewriter(progress: f32, params: &mut DrawTextureParams, columns: u32) {
+    let revealed = typewriter_revealed_fraction(progress, columns);
+
+    // Clip the sprite to only its revealed left side, keeping the same scale
+    if let Some(mut source) = params.source {
+        source.w *= revealed;
+        params.source = Some(source);
+    }
+    if let Some(mut dest_size) = params.dest_size {
+        dest_size.x *= revealed;
+        params.dest_size = Some(dest_size);
+    }
+}
+
+/// Applies the Jitter effect.
+/// Unlike Shake this does not die down over time, and it picks a new random offset every frame (so it is frame-rate
+/// dependent). It is best used as a start effect lasting the whole animation. For deterministic tests, seed macroquad's
+/// rand with `rand::srand` and step the sprite with `update_with_dt`.
+fn apply_jitter(x_pos: &mut X, y_pos: &mut Y, amplitude_x: f32, amplitude_y: f32) {
+    *x_pos += rand::gen_range(-1.0, 1.0) * amplitude_x;
+    *y_pos += rand::gen_range(-1.0, 1.0) * amplitude_y;
+}
+
+/// Scales the drawn size of the sprite (or its tile size if no dest_size is set), keeping it centered on the same point
+fn scale_centered(
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    scale_x: f32,
+    scale_y: f32,
+) {
+    let size = params
+        .dest_size
+        .unwrap_or_else(|| Vec2::new(tile_width, tile_height));
+    let scaled_size = Vec2::new(size.x * scale_x, size.y * scale_y);
+
+    *x_pos += (size.x - scaled_size.x) / 2.0;
+    *y_pos += (size.y - scaled_size.y) / 2.0;
+    params.dest_size = Some(scaled_size);
+}
+
+/// Applies the Swirl effect
+fn apply_swirl(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    speed: f32,
+) {
+    params.rotation = progress * speed * 4.0 * std::f32::consts::PI;
+    let scale = 1.0 - progress;
+    scale_centered(params, x_pos, y_pos, tile_width, tile_height, scale, scale);
+}
+
+/// Applies the SwirlIn effect
+fn apply_swirl_in(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    speed: f32,
+) {
+    params.rotation = (1.0 - progress) * speed * 4.0 * std::f32::consts::PI; // Spin slows down as it settles
+    scale_centered(
+        params,
+        x_pos,
+        y_pos,
+        tile_width,
+        tile_height,
+        progress,
+        progress,
+    );
+}
+
+/// Applies the Stretch effect, StretchPulse reuses it with an oscillating progress
+fn apply_stretch(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    (scale_x, scale_y): (f32, f32),
+) {
+    scale_centered(
+        params,
+        x_pos,
+        y_pos,
+        tile_width,
+        tile_height,
+        lerp(1.0, scale_x, progress),
+        lerp(1.0, scale_y, progress),
+    );
+}
+
+/// Decides whether the current frame should be dropped (not drawn at all) for the DropFrame effect.
+/// This is purely cosmetic and random, so it does not play well with deterministic replays.
+pub(crate) fn should_drop_frame(probability: f32) -> bool {
+    rand::gen_range(0.0, 1.0) < probability
+}
+
+/// Applies the LerpToColor effect
+fn apply_lerp_to_color(
+    progress: f32,
+    color: &mut Color,
+    start_color: &EffectColor,
+    end_color: &EffectColor,
+) {
+    let target_color = start_color.lerp(end_color, progress);
+    color.r = target_color.r;
+    color.g = target_color.g;
+    color.b = target_color.b;
+}
+
+/// Applies the FlickerAlpha effect.
+/// Like Jitter this picks a new random alpha every frame, so it is non-deterministic and frame-rate dependent.
+/// For deterministic tests, seed macroquad's rand with `rand::srand` and step the sprite with `update_with_dt`.
+fn apply_flicker_alpha(color: &mut Color, min_alpha: f32, max_alpha: f32) {
+    // Clamped to valid alphas and ordered, so swapped bounds still flicker within the same range
+    let min_alpha = min_alpha.clamp(0.0, 1.0);
+    let max_alpha = max_alpha.clamp(0.0, 1.0);
+    let (low, high) = if min_alpha <= max_alpha {
+        (min_alpha, max_alpha)
+    } else {
+        (max_alpha, min_alpha)
+    };
+    color.a = rand::gen_range(low, high);
+}
+
+/// Applies the StaticNoise effect.
+/// A new random offset is picked for each color channel every frame, so like Jitter it is non-deterministic.
+fn apply_static_noise(color: &mut Color, intensity: f32) {
+    color.r = (color.r + rand::gen_range(-intensity, intensity)).clamp(0.0, 1.0);
+    color.g = (color.g + rand::gen_range(-intensity, intensity)).clamp(0.0, 1.0);
+    color.b = (color.b + rand::gen_range(-intensity, intensity)).clamp(0.0, 1.0);
+}
+
+/// Applies the ShockWave effect, pushing every edge of the sprite outward from its center and back
+fn apply_shock_wave(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    radius: f32,
+) {
+    // Zero at the start and end, peaking halfway through
+    let displacement = radius * (progress * std::f32::consts::PI).sin();
+    let size = params
+        .dest_size
+        .unwrap_or_else(|| Vec2::new(tile_width, tile_height));
+
+    *x_pos -= displacement;
+    *y_pos -= displacement;
+    params.dest_size = Some(Vec2::new(
+        size.x + displacement * 2.0,
+        size.y + displacement * 2.0,
+    ));
+}
+
+/// Applies the Squint effect, which closes towards its maximum compression halfway through then reopens
+fn apply_squint(progress: f32, params: &mut DrawTextureParams, y_pos: &mut Y, intensity: f32) {
+    let squash_amount = intensity * (progress * std::f32::consts::PI).sin();
+    apply_squash_vertical_centered(params, y_pos, squash_amount);
+}
+
+/// Applies the Inflate effect, which grows from nothing past full size then settles back
+fn apply_inflate(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    overshoot: f32,
+) {
+    let peak_progress = 0.6;
+    let peak_scale = 1.0 + overshoot;
+    let scale = if progress < peak_progress {
+        // Ease out while growing, so it pops in quickly
+        peak_scale * (progress / peak_progress * std::f32::consts::FRAC_PI_2).sin()
+    } else {
+        let settle_progress = (progress - peak_progress) / (1.0 - peak_progress);
+        lerp(peak_scale, 1.0, 1.0 - (1.0 - settle_progress).powi(2))
+    };
+    scale_centered(params, x_pos, y_pos, tile_width, tile_height, scale, scale);
+}
+
+/// Applies the Deflate effect, the counterpart to Inflate, which shrinks towards `-undershoot` so the sprite snaps
+/// to nothing slightly before the effect ends
+fn apply_deflate(
+    progress: f32,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    undershoot: f32,
+) {
+    // Ease in while shrinking, so it lingers at full size before popping out
+    let scale = lerp(1.0, -undershoot, progress.powi(2)).max(0.0);
+    scale_centered(params, x_pos, y_pos, tile_width, tile_height, scale, scale);
+}
+
+/// Returns the (radius, alpha) of ring number `ring` (1 to `rings`) of the Ripple effect, outer rings being fainter
+pub(crate) fn ripple_ring(progress: f32, intensity: f32, ring: u32, rings: u32) -> (f32, f32) {
+    let ring_fraction = ring as f32 / rings.max(1) as f32;
+    let radius = intensity * progress * ring_fraction;
+    let alpha = (1.0 - ring_fraction) * 0.3;
+    (radius, alpha)
+}
+
+/// Applies the Tremble effect.
+/// Uses the same angle as Shake, but keeps a constant amplitude and shakes each axis at its own frequency.
+fn apply_tremble(
+    progress: f32,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    horizontal_intensity: f32,
+    vertical_intensity: f32,
+) {
+    let angle = progress * std::f32::consts::PI * 10.0;
+    *x_pos += horizontal_intensity * angle.sin();
+    *y_pos += vertical_intensity * (angle * 1.7).sin();
+}
+
+/// Applies the LightningBurst effect, which has much sharper flashes than Blinking and no hold at the flash color
+fn apply_lightning_burst(
+    progress: f32,
+    color: &mut Color,
+    flash_color: &EffectColor,
+    flashes: u32,
+) {
+    let flash_duration = 1.0 / flashes.max(1) as f32;
+    let flash_progress = (progress / flash_duration) % 1.0;
+
+    let rise_time = 0.1; // Time to transition to the flash color
+    let fall_time = 0.2; // Time to transition back to original color
+
+    let flash_intensity = if flash_progress < rise_time {
+        flash_progress / rise_time
+    } else if flash_progress < rise_time + fall_time {
+        1.0 - (flash_progress - rise_time) / fall_time
+    } else {
+        0.0
+    };
+
+    let target_color = flash_color.to_color();
+    color.r = lerp(color.r, target_color.r, flash_intensity);
+    color.g = lerp(color.g, target_color.g, flash_intensity);
+    color.b = lerp(color.b, target_color.b, flash_intensity);
+}
+
+/// Applies the CycleFrameEffect effect.
+/// The AnimatedSprite draw methods resolve the inner effect from the current frame (see `AnimationEffect::effect_for_frame`)
+/// before applying it, so this is only reached when applying the effect directly. Without a frame to go on,
+/// the inner effects are spread evenly across the effect's progress instead.
+#[allow(clippy::too_many_arguments)]
+fn apply_cycle_frame_effect(
+    progress: f32,
+    color: &mut Color,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    effects: &[AnimationEffect],
+) {
+    if effects.is_empty() {
+        return;
+    }
+    let index = ((progress * effects.len() as f32) as usize).min(effects.len() - 1);
+    effects[index].apply(
+        progress,
+        color,
+        params,
+        x_pos,
+        y_pos,
+        tile_width,
+        tile_height,
+    );
+}
+
+/// Applies the After effect, which applies the inner effect once the progress reaches the threshold,
+/// with its progress remapped from `[threshold, 1.0]` to `[0.0, 1.0]`
+#[allow(clippy::too_many_arguments)]
+fn apply_after(
+    progress: f32,
+    color: &mut Color,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    (threshold, effect): (f32, &AnimationEffect),
+) {
+    let Some(inner_progress) = after_progress(progress, threshold) else {
+        return;
+    };
+    effect.apply(
+        inner_progress,
+        color,
+        params,
+        x_pos,
+        y_pos,
+        tile_width,
+        tile_height,
+    );
+}
+
+/// Internal, remaps the progress to the progress of the effect nested in After, or None before the threshold is reached
+fn after_progress(progress: f32, threshold: f32) -> Option<f32> {
+    if progress < threshold {
+        return None;
+    }
+    let window = 1.0 - threshold;
+    Some(if window > 0.0 {
+        (progress - threshold) / window
+    } else {
+        1.0
+    })
+}
+
+/// Applies the Before effect, which applies the inner effect until the progress reaches the threshold,
+/// with its progress remapped from `[0.0, threshold]` to `[0.0, 1.0]`.
+/// Nesting it inside After (or the other way around) windows the inner effect between two thresholds.
+#[allow(clippy::too_many_arguments)]
+fn apply_before(
+    progress: f32,
+    color: &mut Color,
+    params: &mut DrawTextureParams,
+    x_pos: &mut X,
+    y_pos: &mut Y,
+    tile_width: f32,
+    tile_height: f32,
+    (threshold, effect): (f32, &AnimationEffect),
+) {
+    let Some(inner_progress) = before_progress(progress, threshold) else {
+        return;
+    };
+    effect.apply(
+        inner_progress,
+        color,
+        params,
+        x_pos,
+        y_pos,
+        tile_width,
+        tile_height,
+    );
+}
+
+/// Internal, remaps the progress to the progress of the effect nested in Before, or None once the threshold is reached
+fn before_progress(progress: f32, threshold: f32) -> Option<f32> {
+    if progress >= threshold {
+        return None;
+    }
+    Some(progress / threshold)
+}
+
 fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The draw state an effect is applied on top of: (color, params, x_pos, y_pos)
+    type DrawState = (Color, DrawTextureParams, X, Y);
+
+    fn apply_at_half(effect: &AnimationEffect) -> DrawState {
+        apply_at(effect, 0.5)
+    }
+
+    fn apply_at(effect: &AnimationEffect, progress: f32) -> DrawState {
+        let mut color = Color::new(0.8, 0.6, 0.4, 1.0);
+        let mut params = DrawTextureParams {
+            source: Some(Rect::new(32.0, 64.0, 32.0, 32.0)),
+            dest_size: Some(Vec2::new(64.0, 64.0)),
+            ..Default::default()
+        };
+        let mut x_pos = 100.0;
+        let mut y_pos = 200.0;
+
+        // Seeded so effects using randomness (Glitch) are repeatable
+        rand::srand(42);
+        effect.apply(
+            progress,
+            &mut color,
+            &mut params,
+            &mut x_pos,
+            &mut y_pos,
+            32.0,
+            32.0,
+        );
+        (color, params, x_pos, y_pos)
+    }
+
+    fn assert_same_draw_state(expected: &DrawState, actual: &DrawState, name: &str) {
+        assert_eq!(expected.0, actual.0, "{name}: color");
+        assert_eq!(expected.1.source, actual.1.source, "{name}: source");
+        assert_eq!(
+            expected.1.dest_size, actual.1.dest_size,
+            "{name}: dest_size"
+        );
+        assert_eq!(expected.1.rotation, actual.1.rotation, "{name}: rotation");
+        assert_eq!(expected.1.flip_x, actual.1.flip_x, "{name}: flip_x");
+        assert_eq!(expected.1.flip_y, actual.1.flip_y, "{name}: flip_y");
+        assert_eq!(expected.2, actual.2, "{name}: x_pos");
+        assert_eq!(expected.3, actual.3, "{name}: y_pos");
+    }
+
+    /// One of every non-custom effect variant. SlideIn/SlideOut leave out SlideDirection::Right/Bottom as those slide from the
+    /// screen edge, which queries the screen size. Mirror only offsets by the sprite's size, so any direction works there.
+    fn all_effects() -> Vec<AnimationEffect> {
+        vec![
+            AnimationEffect::FadeIn,
+            AnimationEffect::FadeOut,
+            AnimationEffect::SlideIn(SlideDirection::Left),
+            AnimationEffect::SlideOut(SlideDirection::Custom(10.0, -20.0)),
+            AnimationEffect::Spin,
+            AnimationEffect::Pulse(1.5),
+            AnimationEffect::Blinking(EffectColor::Red, 3),
+            AnimationEffect::Shake(4.0),
+            AnimationEffect::Wobble(2.0),
+            AnimationEffect::Bounce(20.0, 3),
+            AnimationEffect::BasicFlip(FlipDirection::Vertical),
+            AnimationEffect::Glitch(0.8),
+            AnimationEffect::ShearLeft(0.5),
+            AnimationEffect::ShearRight(0.5),
+            AnimationEffect::SquashFlipVertical(0.3),
+            AnimationEffect::SquashFlipHorizontal(0.3),
+            AnimationEffect::ColorCycle(vec![
+                EffectColor::Cyan,
+                EffectColor::Custom(0.1, 0.2, 0.3),
+                EffectColor::Black,
+            ]),
+            AnimationEffect::Mirror(SlideDirection::Bottom),
+            AnimationEffect::Silhouette(EffectColor::Magenta),
+            AnimationEffect::StepFadeIn(4),
+            AnimationEffect::StepFadeOut(0),
+            AnimationEffect::GlowPulse(EffectColor::White, 0.6),
+            AnimationEffect::HeatDistortion(5.0),
+            AnimationEffect::Typewriter(EffectColor::Black, 8),
+            AnimationEffect::Jitter(1.5, 0.5),
+            AnimationEffect::Swirl(1.0),
+            AnimationEffect::SwirlIn(2.0),
+            AnimationEffect::Stretch(0.8, 1.3),
+            AnimationEffect::StretchPulse(1.2, 0.9),
+            AnimationEffect::DropFrame(0.3),
+            AnimationEffect::Freeze(0.25),
+            AnimationEffect::LerpToColor(EffectColor::White, EffectColor::Green),
+            AnimationEffect::FlickerAlpha(0.3, 0.9),
+            AnimationEffect::StaticNoise(0.2),
+            AnimationEffect::ShockWave(6.0),
+            AnimationEffect::Squint(0.6),
+            AnimationEffect::Inflate(0.25),
+            AnimationEffect::Deflate(0.25),
+            AnimationEffect::Ripple(12.0, 3),
+            AnimationEffect::Tremble(0.5, 2.0),
+            AnimationEffect::LightningBurst(EffectColor::White, 4),
+            AnimationEffect::CycleFrameEffect(vec![
+                AnimationEffect::FadeIn,
+                AnimationEffect::Shake(2.0),
+            ]),
+            AnimationEffect::After(0.25, Box::new(AnimationEffect::FadeOut)),
+            AnimationEffect::Before(
+                0.75,
+                Box::new(AnimationEffect::After(0.5, Box::new(AnimationEffect::Spin))),
+            ),
+        ]
+    }
+
+    #[test]
+    fn effects_round_trip_through_json() {
+        for effect in all_effects() {
+            let json = serde_json::to_string(&effect).unwrap();
+            let restored: AnimationEffect = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(effect, restored);
+            assert_same_draw_state(&apply_at_half(&effect), &apply_at_half(&restored), &json);
+        }
+    }
+
+    #[test]
+    fn effect_descriptions_start_with_their_name() {
+        for effect in all_effects() {
+            let debug = format!("{:?}", effect);
+            assert!(debug.starts_with(effect.name()), "{}", debug);
+            assert!(effect.to_string().starts_with(effect.name()), "{}", debug);
+        }
+        assert_eq!(
+            AnimationEffect::Bounce(20.0, 3).to_string(),
+            "Bounce(height=20.0, bounces=3)"
+        );
+    }
+
+    /// Internal, gets the alpha an effect draws with at each of the given progresses.
+    fn alphas_at(effect: &AnimationEffect, progresses: &[f32]) -> Vec<f32> {
+        progresses
+            .iter()
+            .map(|&progress| apply_at(effect, progress).0.a)
+            .collect()
+    }
+
+    /// Internal, gets the (x_pos, dest width) an effect draws with at the given progress, starting from 100.0 and 64.0.
+    fn horizontal_bounds_at(effect: &AnimationEffect, progress: f32) -> (X, f32) {
+        let (_, params, x_pos, _) = apply_at(effect, progress);
+        (x_pos, params.dest_size.unwrap().x)
+    }
+
+    #[test]
+    fn step_fades_move_in_whole_steps() {
+        let progresses = [0.0, 0.24, 0.25, 0.99, 1.0];
+        assert_eq!(
+            alphas_at(&AnimationEffect::StepFadeIn(4), &progresses),
+            vec![0.0, 0.0, 0.25, 0.75, 1.0]
+        );
+        assert_eq!(
+            alphas_at(&AnimationEffect::StepFadeOut(4), &progresses),
+            vec![1.0, 1.0, 0.75, 0.25, 0.0]
+        );
+        assert_eq!(
+            alphas_at(&AnimationEffect::StepFadeIn(0), &[0.0, 0.5, 1.0]),
+            vec![0.0, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn typewriter_reveals_whole_columns() {
+        assert_eq!(typewriter_revealed_fraction(0.0, 4), 0.0);
+        assert_eq!(typewriter_revealed_fraction(0.3, 4), 0.25);
+        assert_eq!(typewriter_revealed_fraction(0.5, 4), 0.5);
+        assert_eq!(typewriter_revealed_fraction(1.0, 4), 1.0);
+        assert_eq!(typewriter_revealed_fraction(0.5, 0), 0.0);
+
+        let effect = AnimationEffect::Typewriter(EffectColor::Black, 4);
+        let (_, params, _, _) = apply_at(&effect, 0.3);
+        assert_eq!(params.source.unwrap().w, 8.0);
+        assert_eq!(params.dest_size.unwrap().x, 16.0);
+    }
+
+    #[test]
+    fn lerp_to_color_reaches_both_colors_and_keeps_alpha() {
+        let effect = AnimationEffect::LerpToColor(EffectColor::Red, EffectColor::Blue);
+        assert_eq!(apply_at(&effect, 0.0).0, Color::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(apply_at(&effect, 0.5).0, Color::new(0.5, 0.0, 0.5, 1.0));
+        assert_eq!(apply_at(&effect, 1.0).0, Color::new(0.0, 0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn inflate_overshoots_then_settles() {
+        let effect = AnimationEffect::Inflate(0.25);
+        assert_eq!(horizontal_bounds_at(&effect, 0.0), (132.0, 0.0));
+        assert_eq!(horizontal_bounds_at(&effect, 0.6), (92.0, 80.0));
+        assert_eq!(horizontal_bounds_at(&effect, 1.0), (100.0, 64.0));
+    }
+
+    #[test]
+    fn deflate_vanishes_before_the_end() {
+        let effect = AnimationEffect::Deflate(0.25);
+        assert_eq!(horizontal_bounds_at(&effect, 0.0), (100.0, 64.0));
+        let (_, width) = horizontal_bounds_at(&effect, 0.5);
+        assert!(width > 0.0 && width < 64.0);
+        assert_eq!(horizontal_bounds_at(&effect, 0.95), (132.0, 0.0));
+        assert_eq!(horizontal_bounds_at(&effect, 1.0), (132.0, 0.0));
+    }
+
+    #[test]
+    fn shock_wave_peaks_half_way_and_settles() {
+        let effect = AnimationEffect::ShockWave(6.0);
+        assert_eq!(horizontal_bounds_at(&effect, 0.0), (100.0, 64.0));
+
+        let (_, params, x_pos, y_pos) = apply_at(&effect, 0.5);
+        assert_eq!((x_pos, y_pos), (94.0, 194.0));
+        assert_eq!(params.dest_size, Some(Vec2::new(76.0, 76.0)));
+
+        let (x_pos, width) = horizontal_bounds_at(&effect, 1.0);
+        assert!((x_pos - 100.0).abs() < 1e-4 && (width - 64.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn after_remaps_progress_from_its_threshold() {
+        let after = AnimationEffect::After(0.5, Box::new(AnimationEffect::FadeIn));
+        assert_eq!(
+            alphas_at(&after, &[0.0, 0.25, 0.5, 0.75, 1.0]),
+            vec![1.0, 1.0, 0.0, 0.5, 1.0]
+        );
+
+        // With no progress left after the threshold, the inner effect jumps straight to its end
+        let instant = AnimationEffect::After(1.0, Box::new(AnimationEffect::FadeOut));
+        assert_eq!(alphas_at(&instant, &[0.5, 1.0]), vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn after_resolves_drawn_effects_from_its_threshold() {
+        let after =
+            AnimationEffect::After(0.5, Box::new(AnimationEffect::Mirror(SlideDirection::Left)));
+        assert_eq!(after.resolve_at_progress(0, 0.25), None);
+        assert_eq!(
+            after.resolve_at_progress(0, 0.75),
+            Some((&AnimationEffect::Mirror(SlideDirection::Left), 0.5))
+        );
+
+        let cycled = AnimationEffect::CycleFrameEffect(vec![
+            AnimationEffect::FadeIn,
+            AnimationEffect::After(0.5, Box::new(AnimationEffect::Ripple(4.0, 2))),
+        ]);
+        assert_eq!(
+            cycled.resolve_at_progress(0, 0.25),
+            Some((&AnimationEffect::FadeIn, 0.25))
+        );
+        assert_eq!(cycled.resolve_at_progress(1, 0.25), None);
+        assert_eq!(
+            cycled.resolve_at_progress(1, 1.0),
+            Some((&AnimationEffect::Ripple(4.0, 2), 1.0))
+        );
+    }
+
+    #[test]
+    fn before_remaps_progress_up_to_its_threshold() {
+        let before = AnimationEffect::Before(0.5, Box::new(AnimationEffect::FadeIn));
+        assert_eq!(
+            alphas_at(&before, &[0.0, 0.25, 0.5, 0.75, 1.0]),
+            vec![0.0, 0.5, 1.0, 1.0, 1.0]
+        );
+
+        let window = AnimationEffect::After(
+            0.25,
+            Box::new(AnimationEffect::Before(
+                0.5,
+                Box::new(AnimationEffect::FadeIn),
+            )),
+        );
+        assert_eq!(
+            alphas_at(&window, &[0.0, 0.25, 0.4375, 0.625, 1.0]),
+            vec![1.0, 0.0, 0.5, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn before_resolves_drawn_effects_up_to_its_threshold() {
+        let before = AnimationEffect::Before(
+            0.5,
+            Box::new(AnimationEffect::Typewriter(EffectColor::Black, 4)),
+        );
+        assert_eq!(
+            before.resolve_at_progress(0, 0.25),
+            Some((&AnimationEffect::Typewriter(EffectColor::Black, 4), 0.5))
+        );
+        assert_eq!(before.resolve_at_progress(0, 0.5), None);
+
+        let window = AnimationEffect::After(
+            0.25,
+            Box::new(AnimationEffect::Before(
+                0.5,
+                Box::new(AnimationEffect::Silhouette(EffectColor::Black)),
+            )),
+        );
+        assert_eq!(window.resolve_at_progress(0, 0.0), None);
+        assert_eq!(
+            window.resolve_at_progress(0, 0.4375),
+            Some((&AnimationEffect::Silhouette(EffectColor::Black), 0.5))
+        );
+        assert_eq!(window.resolve_at_progress(0, 0.625), None);
+    }
+
+    /// Internal, asserts the color's rgb matches the expected rgb within float rounding, ignoring alpha.
+    fn assert_rgb_near(actual: Color, (r, g, b): (f32, f32, f32)) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+        assert!(
+            close(actual.r, r) && close(actual.g, g) && close(actual.b, b),
+            "{:?} is not close to ({}, {}, {})",
+            actual,
+            r,
+            g,
+            b
+        );
+    }
+
+    #[test]
+    fn lightning_burst_flashes_sharply_then_returns() {
+        let original = (0.8, 0.6, 0.4);
+        let effect = AnimationEffect::LightningBurst(EffectColor::White, 1);
+        assert_rgb_near(apply_at(&effect, 0.0).0, original);
+        assert_rgb_near(apply_at(&effect, 0.05).0, (0.9, 0.8, 0.7));
+        assert_rgb_near(apply_at(&effect, 0.1).0, (1.0, 1.0, 1.0));
+        assert_rgb_near(apply_at(&effect, 0.2).0, (0.9, 0.8, 0.7));
+        assert_rgb_near(apply_at(&effect, 0.3).0, original);
+        assert_rgb_near(apply_at(&effect, 1.0).0, original);
+        assert_eq!(apply_at(&effect, 0.1).0.a, 1.0);
+
+        // Each flash takes an equal share of the progress, so the second flash peaks at 0.5 + 0.1 * 0.5
+        let twice = AnimationEffect::LightningBurst(EffectColor::Black, 2);
+        assert_rgb_near(apply_at(&twice, 0.05).0, (0.0, 0.0, 0.0));
+        assert_rgb_near(apply_at(&twice, 0.3).0, original);
+        assert_rgb_near(apply_at(&twice, 0.55).0, (0.0, 0.0, 0.0));
+        assert_rgb_near(apply_at(&twice, 0.65).0, original);
+    }
+
+    #[test]
+    fn ripple_rings_spread_out_and_fade() {
+        for ring in 1..=3 {
+            assert_eq!(ripple_ring(0.0, 12.0, ring, 3).0, 0.0);
+        }
+
+        // The first ring stays closest and most visible, while the last reaches the full radius fully faded
+        assert_eq!(ripple_ring(0.5, 12.0, 1, 3), (2.0, 0.2));
+        assert_eq!(ripple_ring(0.5, 12.0, 3, 3), (6.0, 0.0));
+        assert_eq!(ripple_ring(1.0, 12.0, 1, 3), (4.0, 0.2));
+        assert_eq!(ripple_ring(1.0, 12.0, 3, 3), (12.0, 0.0));
+
+        // 0 rings is treated as 1 rather than dividing by zero
+        assert_eq!(ripple_ring(1.0, 12.0, 1, 0), (12.0, 0.0));
+    }
+
+    #[cfg(feature = "custom_effects")]
+    #[test]
+    fn custom_effects_are_never_equal() {
+        let effect = AnimationEffect::new_custom(|_, _, _, _, _, _, _| {});
+        assert_ne!(effect, effect.clone());
+    }
+
+    #[cfg(feature = "custom_effects")]
+    #[test]
+    fn named_custom_effect_round_trips_through_json() {
+        let effect = AnimationEffect::new_custom_named(
+            "test_half_alpha",
+            |progress, color, _, _, _, _, _| {
+                color.a = progress / 2.0;
+            },
+        );
+
+        assert!(crate::list_registered_custom_effects().contains(&"test_half_alpha"));
+
+        let json = serde_json::to_string(&effect).unwrap();
+        assert_eq!(json, r#"{"CustomNamed":"test_half_alpha"}"#);
+
+        let restored: AnimationEffect = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, effect);
+        assert_same_draw_state(&apply_at_half(&effect), &apply_at_half(&restored), &json);
+        assert_eq!(apply_at_half(&restored).0.a, 0.25);
+
+        assert!(serde_json::from_str::<AnimationEffect>(r#"{"CustomNamed":"missing"}"#).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Blue, Green, Red, Seconds, X, Y};
+use crate::{Seconds, X, Y};
 use macroquad::{
     color::Color,
     window::{screen_height, screen_width},
@@ -6,14 +6,66 @@ use macroquad::{
 use serde::{Deserialize, Serialize};
 
 /// An internally used type for keeping track of when to start an effect
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum EffectTimeTarget {
     Start(Seconds),
     End(Seconds),
+    /// Plays the effect for `duration` seconds at the start of every `period` seconds, for as long as the animation plays
+    Repeating {
+        period: Seconds,
+        duration: Seconds,
+    },
+}
+
+/// Easing functions which remap an effect's linear progress (0.0 to 1.0), changing how it accelerates over its duration
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum EasingFunction {
+    #[default]
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+    EaseInSine,
+    EaseOutSine,
+    EaseInOutSine,
+}
+
+impl EasingFunction {
+    /// Remaps the linear progress (0.0 to 1.0) through the easing function
+    pub fn apply(&self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+        match self {
+            EasingFunction::Linear => t,
+            EasingFunction::EaseInQuad => t * t,
+            EasingFunction::EaseOutQuad => 1.0 - (1.0 - t).powi(2),
+            EasingFunction::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            EasingFunction::EaseInCubic => t.powi(3),
+            EasingFunction::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            EasingFunction::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            EasingFunction::EaseInSine => 1.0 - (t * std::f32::consts::FRAC_PI_2).cos(),
+            EasingFunction::EaseOutSine => (t * std::f32::consts::FRAC_PI_2).sin(),
+            EasingFunction::EaseInOutSine => -((t * std::f32::consts::PI).cos() - 1.0) / 2.0,
+        }
+    }
 }
 
 /// Represents the direction to slide from/to for the slide animation effects
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SlideDirection {
     Left,
     Right,
@@ -39,10 +91,28 @@ impl SlideDirection {
             SlideDirection::Custom(custom_x, custom_y) => (*custom_x, *custom_y),
         }
     }
+
+    /// Returns the offset to draw the mirrored copy at for the Mirror effect, alongside the direction it should be flipped in.
+    /// Custom offsets flip across whichever axis the offset is largest on.
+    pub fn get_mirror_offset(&self, width: f32, height: f32) -> (X, Y, FlipDirection) {
+        match self {
+            SlideDirection::Left => (-width, 0.0, FlipDirection::Horizontal),
+            SlideDirection::Right => (width, 0.0, FlipDirection::Horizontal),
+            SlideDirection::Top => (0.0, -height, FlipDirection::Vertical),
+            SlideDirection::Bottom => (0.0, height, FlipDirection::Vertical),
+            SlideDirection::Custom(offset_x, offset_y) => {
+                if offset_x.abs() >= offset_y.abs() {
+                    (*offset_x, *offset_y, FlipDirection::Horizontal)
+                } else {
+                    (*offset_x, *offset_y, FlipDirection::Vertical)
+                }
+            }
+        }
+    }
 }
 
 /// A basic color color struct which is fully serializable, and allows specifying an rgb without alpha (important for effects that apply)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EffectColor {
     Red,
     Green,
@@ -70,9 +140,21 @@ impl EffectColor {
             EffectColor::Custom(r, g, b) => Color::new(*r, *g, *b, 1.0),
         }
     }
+
+    /// Linearly interpolates from this EffectColor to `other`, where `t` of 0.0 is this color and 1.0 is `other`
+    pub fn lerp(&self, other: &EffectColor, t: f32) -> Color {
+        let start = self.to_color();
+        let end = other.to_color();
+        Color::new(
+            start.r + (end.r - start.r) * t,
+            start.g + (end.g - start.g) * t,
+            start.b + (end.b - start.b) * t,
+            1.0,
+        )
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FlipDirection {
     Horizontal,
     Vertical,
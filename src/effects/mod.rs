@@ -1,7 +1,15 @@
+pub mod apply;
+#[cfg(feature = "custom_effects")]
 pub mod custom_effect;
+#[cfg(feature = "custom_effects")]
+pub mod custom_effect_registry;
 pub mod effect;
 pub mod effect_helper_types;
 
+pub use apply::*;
+#[cfg(feature = "custom_effects")]
 pub use custom_effect::*;
+#[cfg(feature = "custom_effects")]
+pub use custom_effect_registry::*;
 pub use effect::*;
 pub use effect_helper_types::*;
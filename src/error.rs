@@ -0,0 +1,120 @@
+use std::fmt::{self, Debug};
+
+/// Returned when a key has no animation registered under it in the AnimatedSprite
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeyError<K> {
+    pub key: K,
+}
+
+impl<K: Debug> fmt::Display for UnknownKeyError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no animation is registered under the key {:?}", self.key)
+    }
+}
+
+impl<K: Debug> std::error::Error for UnknownKeyError<K> {}
+
+/// Errors returned by the `_result` suffixed AnimatedSprite methods, describing why the operation failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimatedSpriteError<K> {
+    /// No animation is registered under the key
+    UnknownKey(K),
+    /// The animation has no frames (ex. `Animation::empty()`)
+    EmptyAnimation,
+    /// The requested frame is past the last frame of the animation
+    InvalidFrameIndex { requested: u32, max: u32 },
+    /// The animation has no effect
+    NoEffect,
+}
+
+/// The result type of the `_result` suffixed AnimatedSprite methods
+pub type AnimatedSpriteResult<T, K> = Result<T, AnimatedSpriteError<K>>;
+
+impl<K: Debug> fmt::Display for AnimatedSpriteError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimatedSpriteError::UnknownKey(key) => {
+                write!(f, "no animation is registered under the key {:?}", key)
+            }
+            AnimatedSpriteError::EmptyAnimation => write!(f, "the animation has no frames"),
+            AnimatedSpriteError::InvalidFrameIndex { requested, max } => write!(
+                f,
+                "frame {} is out of range, the last frame is {}",
+                requested, max
+            ),
+            AnimatedSpriteError::NoEffect => write!(f, "the animation has no effect"),
+        }
+    }
+}
+
+impl<K: Debug> std::error::Error for AnimatedSpriteError<K> {}
+
+impl<K> From<UnknownKeyError<K>> for AnimatedSpriteError<K> {
+    fn from(error: UnknownKeyError<K>) -> Self {
+        AnimatedSpriteError::UnknownKey(error.key)
+    }
+}
+
+/// Problems found by `Animation::validate` which would otherwise make the animation misbehave silently
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationValidationError {
+    /// The animation has multiple frames but an fps of 0, so it can never move past its first frame
+    ZeroFpsWithFrames,
+    /// The animation has no rows, so there are no frames to draw
+    EmptyRows,
+    /// The animation has a positive fps but no frames per row to play
+    FramesPerRowIsZero,
+    /// A frame passed to `with_frame_skip` is past the last frame of the animation
+    SkippedFrameOutOfRange { frame: u32, total_frames: u32 },
+    /// Every frame of the animation is skipped, so there are no frames to play
+    AllFramesSkipped,
+    /// The animation's repeating effect lasts longer than the period it repeats at
+    EffectLongerThanPeriod,
+    /// A row of the animation is below the last row of tiles on the texture, found by `Animation::validate_for_texture`
+    RowOutOfTexture { row: u32, texture_rows: u32 },
+    /// Some of the animation's frames would be read from outside of the texture, found by `Animation::validate_for_texture`
+    ExceedsTexture,
+    /// `Animation::subsample_checked` was asked to keep every 0th frame
+    ZeroSubsampleStep,
+}
+
+impl fmt::Display for AnimationValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationValidationError::ZeroFpsWithFrames => {
+                write!(f, "the animation has multiple frames but an fps of 0")
+            }
+            AnimationValidationError::EmptyRows => write!(f, "the animation has no rows"),
+            AnimationValidationError::FramesPerRowIsZero => {
+                write!(f, "the animation has a positive fps but 0 frames per row")
+            }
+            AnimationValidationError::SkippedFrameOutOfRange {
+                frame,
+                total_frames,
+            } => write!(
+                f,
+                "skipped frame {} is out of range, the animation has {} frames",
+                frame, total_frames
+            ),
+            AnimationValidationError::AllFramesSkipped => {
+                write!(f, "every frame of the animation is skipped")
+            }
+            AnimationValidationError::EffectLongerThanPeriod => {
+                write!(f, "the repeating effect lasts longer than its period")
+            }
+            AnimationValidationError::RowOutOfTexture { row, texture_rows } => write!(
+                f,
+                "row {} is out of range, the texture has {} rows of tiles",
+                row, texture_rows
+            ),
+            AnimationValidationError::ExceedsTexture => {
+                write!(f, "the animation's frames extend past the texture")
+            }
+            AnimationValidationError::ZeroSubsampleStep => {
+                write!(f, "the subsample step must be at least 1")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnimationValidationError {}
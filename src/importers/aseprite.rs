@@ -0,0 +1,276 @@
+use crate::Animation;
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// Errors which can occur while importing animations from an Aseprite JSON export
+#[derive(Debug)]
+pub enum AsepriteError {
+    /// The JSON could not be parsed as an Aseprite export
+    Json(serde_json::Error),
+    /// A tag references a frame index which is not in the export
+    MissingFrame { tag: String, frame: usize },
+    /// A tag's frames are not laid out as full rows starting from the first column of the spritesheet
+    UnsupportedLayout { tag: String },
+}
+
+impl fmt::Display for AsepriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsepriteError::Json(error) => write!(f, "invalid Aseprite JSON: {}", error),
+            AsepriteError::MissingFrame { tag, frame } => {
+                write!(f, "tag `{}` references missing frame {}", tag, frame)
+            }
+            AsepriteError::UnsupportedLayout { tag } => write!(
+                f,
+                "frames of tag `{}` are not laid out in rows starting from the first column",
+                tag
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AsepriteError {}
+
+impl From<serde_json::Error> for AsepriteError {
+    fn from(error: serde_json::Error) -> Self {
+        AsepriteError::Json(error)
+    }
+}
+
+#[derive(Deserialize)]
+struct AsepriteFile {
+    frames: AsepriteFrames,
+    meta: AsepriteMeta,
+}
+
+#[derive(Deserialize)]
+struct AsepriteFrame {
+    frame: AsepriteRect,
+    duration: f32,
+}
+
+#[derive(Deserialize)]
+struct AsepriteRect {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Deserialize)]
+struct AsepriteMeta {
+    #[serde(rename = "frameTags", default)]
+    frame_tags: Vec<AsepriteTag>,
+}
+
+#[derive(Deserialize)]
+struct AsepriteTag {
+    name: String,
+    from: usize,
+    to: usize,
+}
+
+/// The frames of an export in file order, which supports both the "array" and "hash" Aseprite JSON formats.
+/// Hash entries are kept in the order they appear, as that order is what tags index into.
+struct AsepriteFrames(Vec<AsepriteFrame>);
+
+impl<'de> Deserialize<'de> for AsepriteFrames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FramesVisitor;
+
+        impl<'de> Visitor<'de> for FramesVisitor {
+            type Value = AsepriteFrames;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an array or map of Aseprite frames")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::new();
+                while let Some(frame) = seq.next_element()? {
+                    frames.push(frame);
+                }
+                Ok(AsepriteFrames(frames))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::new();
+                while let Some((_, frame)) = map.next_entry::<String, AsepriteFrame>()? {
+                    frames.push(frame);
+                }
+                Ok(AsepriteFrames(frames))
+            }
+        }
+
+        deserializer.deserialize_any(FramesVisitor)
+    }
+}
+
+/// Imports animations from the JSON data file exported by Aseprite alongside a spritesheet.
+pub struct AsepriteImporter;
+
+impl AsepriteImporter {
+    /// Parses an Aseprite JSON export (either "array" or "hash" format), returning one named Animation per tag.
+    /// The fps of each animation is approximated from the average duration of its frames.
+    /// Of note, the spritesheet must be exported with each tag's frames in full rows starting from the first column
+    /// (ex. the "By Rows" sheet type with "Split Tags"), as that is the layout Animation uses.
+    pub fn from_json_str(
+        json: &str,
+        tile_width: f32,
+        tile_height: f32,
+    ) -> Result<Vec<(String, Animation)>, AsepriteError> {
+        let file: AsepriteFile = serde_json::from_str(json)?;
+        let frames = file.frames.0;
+
+        file.meta
+            .frame_tags
+            .iter()
+            .map(|tag| {
+                let mut tag_frames = Vec::new();
+                for index in tag.from..=tag.to {
+                    let frame = frames
+                        .get(index)
+                        .ok_or_else(|| AsepriteError::MissingFrame {
+                            tag: tag.name.clone(),
+                            frame: index,
+                        })?;
+                    tag_frames.push(frame);
+                }
+
+                let animation = Self::tag_animation(&tag_frames, tile_width, tile_height)
+                    .ok_or_else(|| AsepriteError::UnsupportedLayout {
+                        tag: tag.name.clone(),
+                    })?;
+                Ok((tag.name.clone(), animation))
+            })
+            .collect()
+    }
+
+    /// Internal, builds the Animation for the frames of a single tag, if they are laid out in full rows from the first column.
+    fn tag_animation(
+        frames: &[&AsepriteFrame],
+        tile_width: f32,
+        tile_height: f32,
+    ) -> Option<Animation> {
+        let mut rows: Vec<u32> = Vec::new();
+        let mut frames_per_row = 0;
+        let mut next_column = 0;
+
+        for frame in frames {
+            let column = (frame.frame.x / tile_width).round() as u32;
+            let row = (frame.frame.y / tile_height).round() as u32;
+
+            if column == 0 {
+                // Every row must be full before moving on to the next one
+                if !rows.is_empty() && next_column != frames_per_row {
+                    return None;
+                }
+                rows.push(row);
+            } else if rows.last() != Some(&row) || column != next_column {
+                return None;
+            }
+
+            next_column = column + 1;
+            if rows.len() == 1 {
+                frames_per_row = next_column;
+            }
+        }
+
+        if rows.is_empty() || next_column != frames_per_row {
+            return None;
+        }
+
+        let average_duration_ms =
+            frames.iter().map(|frame| frame.duration).sum::<f32>() / frames.len() as f32;
+        let fps = (1000.0 / average_duration_ms.max(1.0)).round().max(1.0) as u32;
+
+        Some(Animation::new_multi_row(rows, frames_per_row, fps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Internal, builds an Aseprite export with 32x32 frames at the given (column, row) positions.
+    fn export(positions: &[(u32, u32)], tags: &str, hash: bool) -> String {
+        let frames: Vec<String> = positions
+            .iter()
+            .enumerate()
+            .map(|(index, (column, row))| {
+                let frame = format!(
+                    r#"{{ "frame": {{ "x": {}, "y": {}, "w": 32, "h": 32 }}, "duration": 100 }}"#,
+                    column * 32,
+                    row * 32
+                );
+                if hash {
+                    format!(r#""sheet {}.aseprite": {}"#, index, frame)
+                } else {
+                    frame
+                }
+            })
+            .collect();
+        let frames = if hash {
+            format!("{{ {} }}", frames.join(", "))
+        } else {
+            format!("[ {} ]", frames.join(", "))
+        };
+        format!(
+            r#"{{ "frames": {}, "meta": {{ "frameTags": [ {} ] }} }}"#,
+            frames, tags
+        )
+    }
+
+    const LAYOUT: [(u32, u32); 8] = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (3, 0),
+        (0, 1),
+        (1, 1),
+        (0, 2),
+        (1, 2),
+    ];
+    const TAGS: &str =
+        r#"{ "name": "idle", "from": 0, "to": 3 }, { "name": "walk", "from": 4, "to": 7 }"#;
+
+    #[test]
+    fn imports_tags_from_array_and_hash_exports() {
+        for hash in [false, true] {
+            let animations =
+                AsepriteImporter::from_json_str(&export(&LAYOUT, TAGS, hash), 32.0, 32.0).unwrap();
+            assert_eq!(
+                animations,
+                vec![
+                    ("idle".to_string(), Animation::new(0, 4, 10)),
+                    (
+                        "walk".to_string(),
+                        Animation::new_multi_row(vec![1, 2], 2, 10)
+                    ),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_missing_frames_and_unsupported_layouts() {
+        let missing = r#"{ "name": "run", "from": 6, "to": 8 }"#;
+        assert!(matches!(
+            AsepriteImporter::from_json_str(&export(&LAYOUT, missing, false), 32.0, 32.0),
+            Err(AsepriteError::MissingFrame { frame: 8, .. })
+        ));
+
+        let offset = [(1, 0), (2, 0)];
+        let tag = r#"{ "name": "idle", "from": 0, "to": 1 }"#;
+        assert!(matches!(
+            AsepriteImporter::from_json_str(&export(&offset, tag, false), 32.0, 32.0),
+            Err(AsepriteError::UnsupportedLayout { .. })
+        ));
+
+        let ragged = [(0, 0), (1, 0), (0, 1)];
+        let tag = r#"{ "name": "idle", "from": 0, "to": 2 }"#;
+        assert!(matches!(
+            AsepriteImporter::from_json_str(&export(&ragged, tag, false), 32.0, 32.0),
+            Err(AsepriteError::UnsupportedLayout { .. })
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+use crate::Animation;
+use macroquad::math::Rect;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/// The fps used for imported animations, as LDtk tile references carry no frame timing
+pub const LDTK_DEFAULT_FPS: u32 = 10;
+
+/// Errors which can occur while importing animations from an LDtk entity definition
+#[derive(Debug)]
+pub enum LDtkImportError {
+    /// The JSON could not be parsed as an LDtk entity
+    Json(serde_json::Error),
+    /// The entity has neither a `tileRect` nor any tile fields to build animations from
+    NoTiles { entity: String },
+    /// An animation references a different tileset than the entity, which can't be drawn from a single texture
+    MixedTilesets {
+        animation: String,
+        expected: i64,
+        found: i64,
+    },
+}
+
+impl fmt::Display for LDtkImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LDtkImportError::Json(error) => write!(f, "invalid LDtk entity JSON: {}", error),
+            LDtkImportError::NoTiles { entity } => {
+                write!(f, "entity `{}` has no tile references", entity)
+            }
+            LDtkImportError::MixedTilesets {
+                animation,
+                expected,
+                found,
+            } => write!(
+                f,
+                "animation `{}` uses tileset {} but the entity uses tileset {}",
+                animation, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LDtkImportError {}
+
+impl From<serde_json::Error> for LDtkImportError {
+    fn from(error: serde_json::Error) -> Self {
+        LDtkImportError::Json(error)
+    }
+}
+
+/// An entity definition (`identifier`/`tileRect`) or entity instance (`__identifier`/`__tile`)
+#[derive(Deserialize)]
+struct LDtkEntity {
+    #[serde(alias = "__identifier")]
+    identifier: String,
+    #[serde(rename = "tilesetId", default)]
+    tileset_id: Option<i64>,
+    #[serde(rename = "tileRect", alias = "__tile", default)]
+    tile_rect: Option<LDtkTileRect>,
+    #[serde(rename = "fieldInstances", default)]
+    field_instances: Vec<LDtkFieldInstance>,
+}
+
+#[derive(Deserialize)]
+struct LDtkTileRect {
+    #[serde(rename = "tilesetUid")]
+    tileset_uid: i64,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+}
+
+#[derive(Deserialize)]
+struct LDtkFieldInstance {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    #[serde(rename = "__type")]
+    field_type: String,
+    #[serde(rename = "__value", default)]
+    value: serde_json::Value,
+}
+
+/// Imports animations from LDtk entities, using their tile references as animation frames.
+pub struct LDtkAnimationImporter;
+
+impl LDtkAnimationImporter {
+    /// Parses an LDtk entity (either a definition or an instance), returning its named Animations.
+    /// The entity's own `tileRect` becomes a single frame Animation under the entity identifier,
+    /// and every `Tile`/`Array<Tile>` field becomes an Animation under the field identifier with its tiles as frames.
+    /// Of note, all animations use `LDTK_DEFAULT_FPS`, which can be changed afterwards through `Animation::fps`.
+    pub fn from_entity_def(
+        entity_json: &str,
+    ) -> Result<HashMap<String, Animation>, LDtkImportError> {
+        let entity: LDtkEntity = serde_json::from_str(entity_json)?;
+
+        let mut tile_animations: Vec<(String, Vec<LDtkTileRect>)> = Vec::new();
+        if let Some(tile_rect) = entity.tile_rect {
+            tile_animations.push((entity.identifier.clone(), vec![tile_rect]));
+        }
+        for field in entity.field_instances {
+            let tiles = match field.field_type.as_str() {
+                "Tile" => vec![field.value],
+                "Array<Tile>" => serde_json::from_value(field.value)?,
+                _ => continue,
+            };
+            let tiles = tiles
+                .into_iter()
+                .filter(|tile| !tile.is_null())
+                .map(serde_json::from_value)
+                .collect::<Result<Vec<LDtkTileRect>, _>>()?;
+            if !tiles.is_empty() {
+                tile_animations.push((field.identifier, tiles));
+            }
+        }
+
+        let expected_tileset = match (entity.tileset_id, tile_animations.first()) {
+            (Some(tileset_id), _) => tileset_id,
+            (None, Some((_, tiles))) => tiles[0].tileset_uid,
+            (None, None) => {
+                return Err(LDtkImportError::NoTiles {
+                    entity: entity.identifier,
+                })
+            }
+        };
+
+        let mut animations = HashMap::new();
+        for (name, tiles) in tile_animations {
+            if let Some(tile) = tiles
+                .iter()
+                .find(|tile| tile.tileset_uid != expected_tileset)
+            {
+                return Err(LDtkImportError::MixedTilesets {
+                    animation: name,
+                    expected: expected_tileset,
+                    found: tile.tileset_uid,
+                });
+            }
+
+            let rects = tiles
+                .iter()
+                .map(|tile| Rect::new(tile.x, tile.y, tile.w, tile.h))
+                .collect();
+            animations.insert(
+                name,
+                Animation::new_from_explicit_rects(rects, LDTK_DEFAULT_FPS),
+            );
+        }
+
+        if animations.is_empty() {
+            return Err(LDtkImportError::NoTiles {
+                entity: entity.identifier,
+            });
+        }
+        Ok(animations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TILE_FIELDS: &str = r#""fieldInstances": [
+        { "__identifier": "walk", "__type": "Array<Tile>", "__value": [
+            { "tilesetUid": 7, "x": 0, "y": 16, "w": 16, "h": 16 },
+            { "tilesetUid": 7, "x": 16, "y": 16, "w": 16, "h": 16 }
+        ] },
+        { "__identifier": "hurt", "__type": "Tile", "__value": { "tilesetUid": 7, "x": 32, "y": 16, "w": 16, "h": 16 } },
+        { "__identifier": "unset", "__type": "Tile", "__value": null },
+        { "__identifier": "health", "__type": "Int", "__value": 3 }
+    ]"#;
+
+    #[test]
+    fn imports_entity_definitions_and_instances() {
+        let definition = format!(
+            r#"{{ "identifier": "Slime", "tilesetId": 7, "tileRect": {{ "tilesetUid": 7, "x": 0, "y": 0, "w": 16, "h": 16 }}, {} }}"#,
+            TILE_FIELDS
+        );
+        let instance = format!(
+            r#"{{ "__identifier": "Slime", "__tile": {{ "tilesetUid": 7, "x": 0, "y": 0, "w": 16, "h": 16 }}, {} }}"#,
+            TILE_FIELDS
+        );
+
+        for json in [definition, instance] {
+            let animations = LDtkAnimationImporter::from_entity_def(&json).unwrap();
+            let mut names: Vec<&str> = animations.keys().map(String::as_str).collect();
+            names.sort();
+            assert_eq!(names, vec!["Slime", "hurt", "walk"]);
+
+            let walk = &animations["walk"];
+            assert_eq!(walk.total_frames(), 2);
+            assert_eq!(walk.fps, LDTK_DEFAULT_FPS);
+            assert_eq!(
+                walk.get_explicit_rect(1),
+                Some(Rect::new(16.0, 16.0, 16.0, 16.0))
+            );
+            assert_eq!(animations["Slime"].total_frames(), 1);
+        }
+    }
+
+    #[test]
+    fn rejects_entities_without_tiles_or_with_mixed_tilesets() {
+        assert!(matches!(
+            LDtkAnimationImporter::from_entity_def(r#"{ "identifier": "Trigger" }"#),
+            Err(LDtkImportError::NoTiles { entity }) if entity == "Trigger"
+        ));
+
+        let mixed = r#"{ "identifier": "Slime", "tilesetId": 7, "fieldInstances": [
+            { "__identifier": "walk", "__type": "Array<Tile>", "__value": [
+                { "tilesetUid": 7, "x": 0, "y": 16, "w": 16, "h": 16 },
+                { "tilesetUid": 9, "x": 16, "y": 16, "w": 16, "h": 16 }
+            ] }
+        ] }"#;
+        assert!(matches!(
+            LDtkAnimationImporter::from_entity_def(mixed),
+            Err(LDtkImportError::MixedTilesets { animation, expected: 7, found: 9 }) if animation == "walk"
+        ));
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "aseprite")]
+pub mod aseprite;
+#[cfg(feature = "ldtk")]
+pub mod ldtk;
+#[cfg(feature = "texturepacker")]
+pub mod texturepacker;
+
+#[cfg(feature = "aseprite")]
+pub use aseprite::*;
+#[cfg(feature = "ldtk")]
+pub use ldtk::*;
+#[cfg(feature = "texturepacker")]
+pub use texturepacker::*;
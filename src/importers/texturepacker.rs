@@ -0,0 +1,241 @@
+use crate::Animation;
+use macroquad::math::Rect;
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+/// The fps used for imported animations, as TexturePacker exports carry no frame timing
+pub const TEXTUREPACKER_DEFAULT_FPS: u32 = 10;
+
+/// Errors which can occur while importing animations from a TexturePacker JSON export
+#[derive(Debug)]
+pub enum ImportError {
+    /// The JSON could not be parsed as a TexturePacker export
+    Json(serde_json::Error),
+    /// A frame was packed rotated, which Animation cannot draw
+    RotatedFrame { frame: String },
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Json(error) => write!(f, "invalid TexturePacker JSON: {}", error),
+            ImportError::RotatedFrame { frame } => write!(
+                f,
+                "frame `{}` is rotated, export with rotation disabled",
+                frame
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+impl From<serde_json::Error> for ImportError {
+    fn from(error: serde_json::Error) -> Self {
+        ImportError::Json(error)
+    }
+}
+
+#[derive(Deserialize)]
+struct TexturePackerFile {
+    frames: TexturePackerFrames,
+}
+
+#[derive(Deserialize)]
+struct TexturePackerFrame {
+    #[serde(default)]
+    filename: String,
+    frame: TexturePackerRect,
+    #[serde(default)]
+    rotated: bool,
+}
+
+#[derive(Deserialize)]
+struct TexturePackerRect {
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+}
+
+/// The frames of an export in file order, which supports both the "array" and "hash" TexturePacker JSON formats.
+/// In the hash format the frame names are the map keys, so they are moved into each frame's `filename`.
+struct TexturePackerFrames(Vec<TexturePackerFrame>);
+
+impl<'de> Deserialize<'de> for TexturePackerFrames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FramesVisitor;
+
+        impl<'de> Visitor<'de> for FramesVisitor {
+            type Value = TexturePackerFrames;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an array or map of TexturePacker frames")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::new();
+                while let Some(frame) = seq.next_element()? {
+                    frames.push(frame);
+                }
+                Ok(TexturePackerFrames(frames))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut frames = Vec::new();
+                while let Some((name, mut frame)) =
+                    map.next_entry::<String, TexturePackerFrame>()?
+                {
+                    frame.filename = name;
+                    frames.push(frame);
+                }
+                Ok(TexturePackerFrames(frames))
+            }
+        }
+
+        deserializer.deserialize_any(FramesVisitor)
+    }
+}
+
+/// Imports animations from the JSON data file exported by TexturePacker alongside a sprite atlas.
+pub struct TexturePackerImporter;
+
+impl TexturePackerImporter {
+    /// Parses a TexturePacker JSON export (either "array" or "hash" format), returning one named Animation per group of frames.
+    /// Frames are grouped by their name without the file extension and trailing frame number (ex. `walk_0.png`, `walk_1.png` → `walk`),
+    /// and ordered by that frame number. Animations are returned in the order their first frame appears in the export.
+    /// Of note, all animations use `TEXTUREPACKER_DEFAULT_FPS`, which can be changed afterwards through `Animation::fps`.
+    pub fn from_json_str(json: &str) -> Result<Vec<(String, Animation)>, ImportError> {
+        let file: TexturePackerFile = serde_json::from_str(json)?;
+
+        let mut groups: Vec<(String, Vec<(u32, Rect)>)> = Vec::new();
+        for frame in file.frames.0 {
+            if frame.rotated {
+                return Err(ImportError::RotatedFrame {
+                    frame: frame.filename,
+                });
+            }
+
+            let (name, number) = Self::split_frame_name(&frame.filename);
+            let rect = Rect::new(frame.frame.x, frame.frame.y, frame.frame.w, frame.frame.h);
+            match groups
+                .iter_mut()
+                .find(|(group_name, _)| *group_name == name)
+            {
+                Some((_, rects)) => rects.push((number, rect)),
+                None => groups.push((name.to_string(), vec![(number, rect)])),
+            }
+        }
+
+        Ok(groups
+            .into_iter()
+            .map(|(name, mut rects)| {
+                rects.sort_by_key(|(number, _)| *number);
+                let rects = rects.into_iter().map(|(_, rect)| rect).collect();
+                (
+                    name,
+                    Animation::new_from_explicit_rects(rects, TEXTUREPACKER_DEFAULT_FPS),
+                )
+            })
+            .collect())
+    }
+
+    /// Internal, splits a frame name into its animation name and frame number (0 if it has none).
+    fn split_frame_name(filename: &str) -> (&str, u32) {
+        let stem = match filename.rsplit_once('.') {
+            Some((stem, _)) if !stem.is_empty() => stem,
+            _ => filename,
+        };
+
+        let name = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+        let number = stem[name.len()..].parse().unwrap_or(0);
+        let name = name.trim_end_matches(['_', '-', ' ']);
+        if name.is_empty() {
+            (stem, 0)
+        } else {
+            (name, number)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Internal, builds a TexturePacker "hash" export with one 32x32 frame per name, laid out in a single row.
+    fn export(names: &[&str], rotated: bool) -> String {
+        let frames: Vec<String> = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                format!(
+                    r#""{}": {{ "frame": {{ "x": {}, "y": 0, "w": 32, "h": 32 }}, "rotated": {} }}"#,
+                    name,
+                    index * 32,
+                    rotated
+                )
+            })
+            .collect();
+        format!(r#"{{ "frames": {{ {} }} }}"#, frames.join(", "))
+    }
+
+    #[test]
+    fn groups_frames_by_name_in_numeric_order() {
+        let names: Vec<String> = (0..=10).rev().map(|n| format!("walk_{}.png", n)).collect();
+        let mut names: Vec<&str> = names.iter().map(String::as_str).collect();
+        names.insert(3, "idle.png");
+
+        let animations = TexturePackerImporter::from_json_str(&export(&names, false)).unwrap();
+        let keys: Vec<&str> = animations.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(keys, vec!["walk", "idle"]);
+
+        // Frames are exported from walk_10 down to walk_0, with idle.png in between walk_8 and walk_7
+        let walk = &animations[0].1;
+        let positions: Vec<f32> = (0..walk.total_frames())
+            .map(|frame| walk.get_explicit_rect(frame).unwrap().x / 32.0)
+            .collect();
+        assert_eq!(
+            positions,
+            vec![11.0, 10.0, 9.0, 8.0, 7.0, 6.0, 5.0, 4.0, 2.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn reads_array_exports_and_rejects_rotated_frames() {
+        let json = r#"{ "frames": [
+            { "filename": "jump-1", "frame": { "x": 32, "y": 0, "w": 32, "h": 32 } },
+            { "filename": "jump-0", "frame": { "x": 0, "y": 0, "w": 32, "h": 32 } }
+        ] }"#;
+        let animations = TexturePackerImporter::from_json_str(json).unwrap();
+        assert_eq!(animations.len(), 1);
+        assert_eq!(animations[0].0, "jump");
+        assert_eq!(animations[0].1.get_explicit_rect(0).unwrap().x, 0.0);
+
+        assert!(matches!(
+            TexturePackerImporter::from_json_str(&export(&["walk_0.png"], true)),
+            Err(ImportError::RotatedFrame { frame }) if frame == "walk_0.png"
+        ));
+    }
+
+    #[test]
+    fn splits_frame_names() {
+        assert_eq!(
+            TexturePackerImporter::split_frame_name("walk_10.png"),
+            ("walk", 10)
+        );
+        assert_eq!(TexturePackerImporter::split_frame_name("run 3"), ("run", 3));
+        assert_eq!(
+            TexturePackerImporter::split_frame_name("idle.png"),
+            ("idle", 0)
+        );
+        assert_eq!(
+            TexturePackerImporter::split_frame_name("042.png"),
+            ("042", 0)
+        );
+        assert_eq!(
+            TexturePackerImporter::split_frame_name(".hidden"),
+            (".hidden", 0)
+        );
+    }
+}
@@ -30,6 +30,30 @@
 //! - **ShearLeft(f32)** and **ShearRight(f32)**: Apply a shearing effect to the sprite. The f32 parameter determines the intensity of the shear.
 //! - **SquashFlipVertical(f32)** and **SquashFlipVertical(f32)**: Squash + flip the sprite either vertically or horizontally. The f32 parameter determines the intensity of the squash.
 //! - **ColorCycle(Vec<EffectColor>)**: Cycle through a palette of colors.
+//! - **Mirror(SlideDirection)**: Draw a faded, flipped copy of the sprite next to it on the given side (reflections, water surfaces).
+//! - **Silhouette(EffectColor)**: Draw the sprite as a flat colored shape, keeping only its outline (shadows, mystery characters).
+//! - **StepFadeIn(u32)** and **StepFadeOut(u32)**: Change the opacity of the sprite in a number of discrete steps, for a retro look.
+//! - **GlowPulse(EffectColor, f32)**: Pulse the sprite's color towards a glow color, without changing its size. The f32 parameter determines the maximum glow intensity (0.0 to 1.0).
+//! - **HeatDistortion(f32)**: Make the sprite shimmer up and down as if seen through heat. The f32 parameter determines the intensity of the shimmer.
+//! - **Typewriter(EffectColor, u32)**: Reveal the sprite from left to right, column by column. The EffectColor fills the unrevealed area, and the u32 parameter specifies the number of columns.
+//! - **Jitter(f32, f32)**: Constantly jitter the sprite around randomly (rumbling engines, trembling objects). The f32 parameters determine the horizontal and vertical amplitude of the jitter.
+//! - **Swirl(f32)** and **SwirlIn(f32)**: Spin the sprite while shrinking it away or growing it in, like being sucked in/out of a portal. The f32 parameter determines the speed of the spin.
+//! - **Stretch(f32, f32)** and **StretchPulse(f32, f32)**: Stretch the sprite towards the given horizontal/vertical scale (ex. anticipating a jump), either once or back and forth.
+//! - **DropFrame(f32)**: Randomly skip drawing the sprite on some frames, for a worn tape/stuttering look. The f32 parameter determines the probability of each frame being dropped.
+//! - **Freeze(Seconds)**: Hold the sprite on its current frame for a number of seconds before it continues animating (hit-stop, impacts).
+//! - **LerpToColor(EffectColor, EffectColor)**: Smoothly blend the sprite from one color to another over the effect (ex. a sprite turning green from poison).
+//! - **FlickerAlpha(f32, f32)**: Randomly flicker the sprite's transparency between a minimum and maximum alpha every frame (fire, magic shields, degraded holograms).
+//! - **StaticNoise(f32)**: Randomly vary the sprite's color every frame like an old TV signal. Low intensities (0.05) give subtle film grain, high intensities (0.5) severe signal degradation.
+//! - **ShockWave(f32)**: Burst the sprite's edges outward from its center and back, like a ring-shaped shockwave. The f32 parameter is the maximum outward displacement in pixels.
+//! - **Squint(f32)**: Compress the sprite vertically towards its center and back open, like a wink or a hit-react squish. The f32 parameter is the maximum fraction of the height to compress by.
+//! - **Inflate(f32)**: Pop the sprite in from nothing, overshooting its full size before settling back (bounce-in entrances). The f32 parameter is the fraction to overshoot by.
+//! - **Deflate(f32)**: Shrink the sprite away, snapping to nothing slightly before the effect ends (pop-out exits, pairs with Inflate). The f32 parameter is how far past nothing to shrink towards.
+//! - **Ripple(f32, u32)**: Draw faint copies of the sprite spreading outward from it in rings, for water impacts, magic and shield hits. The f32 parameter is the maximum radius the rings spread to, the u32 the number of rings.
+//! - **Tremble(f32, f32)**: Shake the sprite with separate horizontal and vertical intensities which don't die down, for direction-biased shaking (fear/cold trembling, engine vibration).
+//! - **LightningBurst(EffectColor, u32)**: Rapidly flash the sprite to a color and back, sharper than Blinking (lightning strikes, electric hits). The u32 parameter is the number of flashes.
+//! - **CycleFrameEffect(Vec<AnimationEffect>)**: Apply a different effect on each frame of the animation, cycling through the provided effects (ex. normal, tinted, shaking).
+//! - **After(f32, Box<AnimationEffect>)**: Only apply the inner effect once the effect's progress reaches a threshold (0.0 to 1.0), with the inner effect playing over the remaining progress (ex. start fading out half-way through).
+//! - **Before(f32, Box<AnimationEffect>)**: Only apply the inner effect until the effect's progress reaches a threshold (0.0 to 1.0), with the inner effect playing over the progress before it. Nest with After to window an effect, ex. `After(0.25, Box::new(Before(0.5, ...)))`.
 //!
 //! ## Basic Usage
 //!
@@ -37,17 +61,14 @@
 //!
 //! Here's a quick example of how to create and use an animated sprite:
 //!
-//! ```rust
-//! use queued_animated_sprites_macroquad::{AnimatedSprite, Animation, AnimationEffect, SlideFrom};
+//! ```rust,no_run
+//! use queued_animated_sprites_macroquad::{AnimatedSprite, Animation};
 //! use macroquad::prelude::*;
 //!
 //! #[macroquad::main("Queued Animated Sprites Demo")]
 //! async fn main() {
 //!     // Load the spritesheet
-//!     let texture = Texture2D::from_file_with_format(
-//!         include_bytes!("slime.png"),
-//!         None
-//!     );
+//!     let texture = load_texture("slime.png").await.unwrap();
 //!
 //!     // Create the animated sprite
 //!     let mut slime = AnimatedSprite::new(
@@ -64,7 +85,7 @@
 //!     );
 //!
 //!     // Using the added attack animation, queue it up and set its duration for 1.5 seconds
-//!     slime.add_animation_to_queue("attack", 1.5);
+//!     slime.add_animation_to_queue_result("attack", 1.5).unwrap();
 //!
 //!     loop {
 //!         clear_background(WHITE);
@@ -90,15 +111,16 @@
 //! Here's an example of how to use animation effects:
 //!
 //! ```rust
-//! use queued_animated_sprites_macroquad::{AnimatedSprite, Animation, AnimationEffect, SlideFrom};
+//! use queued_animated_sprites_macroquad::{AnimatedSprite, Animation, AnimationEffect};
 //!
 //! // ... (previous setup code)
+//! # let mut slime = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 6));
 //!
 //! // Add a spawn animation with fade-in effects
 //! slime.register_animation(
 //!     "spawn",
 //!     Animation::new(2, 4, 8)  // row 2, 4 frames, 8 fps
-//!         .with_start_effect(AnimationEffect::FadeIn, 1) // fade in effect has a duration of 1s from the start of the spawn animation
+//!         .with_start_effect(AnimationEffect::FadeIn, 1.0) // fade in effect has a duration of 1s from the start of the spawn animation
 //! );
 //!
 //! // Add a despawn animation with fade-out
@@ -109,7 +131,7 @@
 //! );
 //!
 //! // Queue the spawn animation which will play for 1 second
-//! slime.add_animation_to_queue("spawn", 1.0);
+//! slime.add_animation_to_queue_result("spawn", 1.0).unwrap();
 //! ```
 //!
 //! In this example, we've added a "spawn" animation that fades in, and a "despawn" animation that fades out.
@@ -131,9 +153,15 @@
 //! The one tradeoff is that custom effects are not serializable (thus less plug-and-play), so this
 //! is an optional feature for advanced users.
 //!
+//! If you do need a custom effect to be serializable, create it with `AnimationEffect::new_custom_named("my_effect", f)`.
+//! This registers the effect under the given name, which is what gets serialized. Of note, every named effect must be
+//! registered this way at startup before any sprites using it are deserialized.
+//!
 //! Here's an example of creating and using a custom "color cycle" effect:
 //!
 //! ```rust
+//! # #[cfg(feature = "custom_effects")]
+//! # {
 //! use queued_animated_sprites_macroquad::{AnimatedSprite, Animation, AnimationEffect};
 //! use macroquad::prelude::*;
 //!
@@ -153,6 +181,7 @@
 //! }
 //!
 //! // ... (previous setup code)
+//! # let mut slime = AnimatedSprite::new(32.0, 32.0, "idle", Animation::new(0, 4, 6));
 //!
 //! // Add an idle animation with the color cycle effect
 //! let color_cycle_animation = Animation::new(0, 4, 6)
@@ -161,17 +190,35 @@
 //! slime.register_animation("idle_color_cycle", color_cycle_animation);
 //!
 //! // Queue the color cycling idle animation, which will play for 3.0 seconds
-//! slime.add_animation_to_queue("idle_color_cycle", 3.0);
+//! slime.add_animation_to_queue_result("idle_color_cycle", 3.0).unwrap();
+//! # }
 //! ```
 //!
 //! This example creates a custom color cycle effect that changes the color of the sprite over time.
 //! The effect is applied to an "idle_color_cycle" animation that lasts for 3 seconds.
+//!
+//! ## Other Optional Features
+//!
+//! - `serialization`: `export_state_to_json` / `restore_state_from_json` for saving and restoring playback state.
+//! - `ron_serialization`: `to_ron_string` / `from_ron_str` for hand-authored RON sprite configs. Of note, `AnimationEffect::Custom` can't round-trip through RON as it is skipped by serde.
+//! - `aseprite`, `texturepacker`, `ldtk`: importers which build `Animation`s from the JSON exported by each tool.
+//! - `callbacks`: `on_animation_complete`, `on_frame_change` and `on_specific_frame` for running code when an animation plays through to its end (ex. with `LoopMode::Once`), changes frame, or reaches a given frame.
 
 pub mod animated_sprite;
 pub mod effects;
+pub mod error;
+#[cfg(any(feature = "aseprite", feature = "ldtk", feature = "texturepacker"))]
+pub mod importers;
+#[cfg(feature = "serialization")]
+pub mod serialization;
 
 pub use animated_sprite::*;
 pub use effects::*;
+pub use error::*;
+#[cfg(any(feature = "aseprite", feature = "ldtk", feature = "texturepacker"))]
+pub use importers::*;
+#[cfg(feature = "serialization")]
+pub use serialization::*;
 
 type AnimationQueueEntry<K> = (K, EffectDuration); // (key, duration)
 pub type X = f32;
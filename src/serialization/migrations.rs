@@ -0,0 +1,110 @@
+use crate::serialization::{IncompatibleSchemaError, CURRENT_SCHEMA_VERSION};
+use serde_json::{json, Value};
+
+/// A migration step, converting exported state from one schema version to the next
+type Migration = fn(Value) -> Value;
+
+/// Migration steps indexed by the version they migrate from, so `MIGRATIONS[n]` converts version n to n + 1.
+/// When the schema changes, bump `CURRENT_SCHEMA_VERSION` and append the step from the previous version here.
+const MIGRATIONS: [Migration; CURRENT_SCHEMA_VERSION as usize] =
+    [migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// Returns the schema version of exported state, where exports from before versioning was added count as version 0.
+pub fn schema_version(state: &Value) -> u32 {
+    state
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .map_or(0, |version| version as u32)
+}
+
+/// Migrates exported state of any supported schema version up to `CURRENT_SCHEMA_VERSION`.
+pub fn migrate_to_current(mut state: Value) -> Result<Value, IncompatibleSchemaError> {
+    let version = schema_version(&state);
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(IncompatibleSchemaError {
+            found_version: version,
+            supported_version: CURRENT_SCHEMA_VERSION,
+        });
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        state = migration(state);
+    }
+    Ok(state)
+}
+
+/// Version 0 was the bare serialized AnimatedSprite, which version 1 wraps in an `AnimatedSpriteState`.
+fn migrate_v0_to_v1(state: Value) -> Value {
+    json!({ "schema_version": 1, "sprite": state })
+}
+
+/// Version 2 added an easing function to every animation's effect, so effects from version 1 are given `Linear` easing.
+fn migrate_v1_to_v2(mut state: Value) -> Value {
+    if let Some(animations) = state
+        .pointer_mut("/sprite/animations")
+        .and_then(Value::as_object_mut)
+    {
+        for animation in animations.values_mut() {
+            if let Some(effect) = animation.get_mut("effect").and_then(Value::as_array_mut) {
+                if effect.len() == 2 {
+                    effect.push(json!("Linear"));
+                }
+            }
+        }
+    }
+    state["schema_version"] = json!(2);
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Internal, a sprite as serialized before easing functions were added to effects.
+    fn unversioned_sprite() -> Value {
+        json!({
+            "animations": {
+                "idle": { "rows": [0], "frames_per_row": 4, "fps": 6, "effect": null },
+                "hit": { "rows": [1], "frames_per_row": 4, "fps": 12, "effect": ["FadeIn", { "Start": 1.0 }] }
+            }
+        })
+    }
+
+    #[test]
+    fn migrates_unversioned_state_to_current() {
+        let state = migrate_to_current(unversioned_sprite()).unwrap();
+        assert_eq!(schema_version(&state), CURRENT_SCHEMA_VERSION);
+        assert_eq!(
+            state.pointer("/sprite/animations/hit/effect"),
+            Some(&json!(["FadeIn", { "Start": 1.0 }, "Linear"]))
+        );
+        assert_eq!(
+            state.pointer("/sprite/animations/idle/effect"),
+            Some(&Value::Null)
+        );
+    }
+
+    #[test]
+    fn migrates_v1_state_to_current() {
+        let state = json!({ "schema_version": 1, "sprite": unversioned_sprite() });
+        assert_eq!(
+            migrate_to_current(state).unwrap(),
+            migrate_to_current(unversioned_sprite()).unwrap()
+        );
+
+        let current = migrate_to_current(unversioned_sprite()).unwrap();
+        assert_eq!(migrate_to_current(current.clone()).unwrap(), current);
+    }
+
+    #[test]
+    fn rejects_state_from_newer_versions() {
+        let state = json!({ "schema_version": CURRENT_SCHEMA_VERSION + 1, "sprite": {} });
+        assert_eq!(
+            migrate_to_current(state),
+            Err(IncompatibleSchemaError {
+                found_version: CURRENT_SCHEMA_VERSION + 1,
+                supported_version: CURRENT_SCHEMA_VERSION,
+            })
+        );
+    }
+}
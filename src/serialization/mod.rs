@@ -0,0 +1,65 @@
+pub mod migrations;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The schema version written by `AnimatedSprite::export_state_to_json`
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// The serialized form of an exported AnimatedSprite, tagged with the schema version it was written with.
+#[derive(Serialize, Deserialize)]
+pub struct AnimatedSpriteState<S> {
+    pub schema_version: u32,
+    pub sprite: S,
+}
+
+/// Returned when exported sprite state was written with a schema version that can't be migrated to the current one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncompatibleSchemaError {
+    pub found_version: u32,
+    pub supported_version: u32,
+}
+
+impl fmt::Display for IncompatibleSchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sprite state has schema version {}, but only versions up to {} are supported",
+            self.found_version, self.supported_version
+        )
+    }
+}
+
+impl std::error::Error for IncompatibleSchemaError {}
+
+/// Errors which can occur while restoring exported sprite state
+#[derive(Debug)]
+pub enum RestoreStateError {
+    /// The JSON could not be parsed as exported sprite state
+    Json(serde_json::Error),
+    /// The state was written with an unsupported schema version
+    IncompatibleSchema(IncompatibleSchemaError),
+}
+
+impl fmt::Display for RestoreStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestoreStateError::Json(error) => write!(f, "invalid sprite state JSON: {}", error),
+            RestoreStateError::IncompatibleSchema(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for RestoreStateError {}
+
+impl From<serde_json::Error> for RestoreStateError {
+    fn from(error: serde_json::Error) -> Self {
+        RestoreStateError::Json(error)
+    }
+}
+
+impl From<IncompatibleSchemaError> for RestoreStateError {
+    fn from(error: IncompatibleSchemaError) -> Self {
+        RestoreStateError::IncompatibleSchema(error)
+    }
+}